@@ -0,0 +1,78 @@
+import { NextRequest } from 'next/server';
+import { ApiError, toApiError } from '@/lib/api-errors';
+import { executeQuery } from '@/lib/query';
+import { checkJsonContentType, readBodyText } from '@/lib/request-body';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+const MAX_BATCH_SIZE = 20;
+
+interface BatchQuery {
+  name: string;
+  params?: Record<string, any>;
+}
+
+interface BatchResult {
+  name: string;
+  data: any;
+  error: string | null;
+}
+
+export const POST = withStats(async function POST(request: NextRequest) {
+  let bodyText: string;
+  try {
+    bodyText = await readBodyText(request);
+  } catch (error) {
+    return toApiError(error, 'batch').toResponse(request);
+  }
+
+  const contentTypeError = checkJsonContentType(request, bodyText);
+  if (contentTypeError) {
+    return contentTypeError.toResponse(request);
+  }
+
+  let body: any;
+  try {
+    body = JSON.parse(bodyText);
+  } catch {
+    return ApiError.invalidQuery('Request body must be valid JSON').toResponse(request);
+  }
+
+  const queries: BatchQuery[] | undefined = body?.queries;
+  if (!Array.isArray(queries)) {
+    return ApiError.invalidQuery('queries must be an array').toResponse(request);
+  }
+
+  if (queries.length > MAX_BATCH_SIZE) {
+    return ApiError.invalidQuery(
+      `Batch size ${queries.length} exceeds maximum of ${MAX_BATCH_SIZE}`
+    ).toResponse(request);
+  }
+
+  // Params are converted key by key, so anything but a plain object is rejected before running the batch
+  const badParams = queries.findIndex(query =>
+    query?.params !== undefined && (query.params === null || typeof query.params !== 'object' || Array.isArray(query.params)));
+  if (badParams !== -1) {
+    return ApiError.invalidQuery(`queries[${badParams}].params must be an object`).toResponse(request);
+  }
+
+  // Execute sequentially so a failing query does not abort the rest of the batch
+  const results: BatchResult[] = [];
+  for (const query of queries) {
+    if (!query || typeof query.name !== 'string' || !query.name) {
+      results.push({ name: '', data: null, error: 'Query name is required' });
+      continue;
+    }
+
+    try {
+      const data = await executeQuery(query.name, query.params || {});
+      results.push({ name: query.name, data, error: null });
+    } catch (error) {
+      logger.error(`Error executing batched query '${query.name}':`, error);
+      results.push({ name: query.name, data: null, error: toApiError(error, query.name).message });
+    }
+  }
+
+  return jsonResponse(request, { results });
+});
@@ -0,0 +1,24 @@
+import { NextRequest } from 'next/server';
+import { HelixError, helixRequest } from '@/lib/helix';
+import { ApiError, toApiError } from '@/lib/api-errors';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  const { id } = await params;
+
+  try {
+    const data = await helixRequest(`edge-details?id=${encodeURIComponent(id)}`);
+    return jsonResponse(request, data);
+  } catch (error) {
+    if (error instanceof HelixError && error.status === 404) {
+      return ApiError.notFound(`Edge '${id}' not found`).toResponse(request);
+    }
+    logger.error(`Error fetching edge '${id}':`, error);
+    return toApiError(error, 'edge-details').toResponse(request);
+  }
+});
@@ -0,0 +1,9 @@
+import { NextRequest } from 'next/server';
+import { healthStatus } from '@/lib/health';
+import { jsonResponse } from '@/lib/response';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(request: NextRequest) {
+  const health = await healthStatus();
+  return jsonResponse(request, health, { status: health.status === 'connected' ? 200 : 503 });
+});
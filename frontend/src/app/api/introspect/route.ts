@@ -0,0 +1,17 @@
+import { NextRequest } from 'next/server';
+import { fetchIntrospect } from '@/lib/introspect';
+import { toApiError } from '@/lib/api-errors';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(request: NextRequest) {
+  try {
+    // Return HelixDB's introspect response as-is
+    const introspectData = await fetchIntrospect();
+    return jsonResponse(request, introspectData);
+  } catch (error) {
+    logger.error('Error fetching introspect data:', error);
+    return toApiError(error, 'introspect').toResponse(request);
+  }
+});
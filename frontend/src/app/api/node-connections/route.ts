@@ -1,56 +1,25 @@
-import { NextRequest, NextResponse } from 'next/server';
+import { NextRequest } from 'next/server';
+import { helixRequest, withQueryParams } from '@/lib/helix';
+import { toApiError } from '@/lib/api-errors';
+import { nodeConnectionsSearchParams, parseNodeConnectionsParams } from '@/lib/params';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
 
-const host = process.env.DOCKER_HOST_INTERNAL || process.env.HELIX_HOST || 'localhost';
-const port = process.env.HELIX_PORT || 6969;
-const cloudUrl = process.env.HELIX_CLOUD_URL;
-
-const helixUrl = cloudUrl ? cloudUrl : `http://${host}:${port}`;
-
-function createNodeConnectionsErrorData() {
-  return {
-    connected_nodes: { values: [] },
-    incoming_edges: { values: [] },
-    outgoing_edges: { values: [] }
-  };
-}
-
-export async function GET(request: NextRequest) {
+export const GET = withStats(async function GET(request: NextRequest) {
   try {
     const url = new URL(request.url);
-    const nodeId = url.searchParams.get('node_id');
-    
-    if (!nodeId) {
-      return NextResponse.json(
-        { error: 'node_id parameter is required' },
-        { status: 400 }
-      );
-    }
+    const params = parseNodeConnectionsParams(url.searchParams);
     
     // Build the request URL for the HelixDB instance
-    const requestUrl = `${helixUrl}/node-connections?node_id=${encodeURIComponent(nodeId)}`;
+    const requestPath = withQueryParams('node-connections', nodeConnectionsSearchParams(params));
     
     // Make direct HTTP request to the HelixDB instance
-    const response = await fetch(requestUrl, {
-      headers: process.env.HELIX_API_KEY ? {
-        'x-api-key': process.env.HELIX_API_KEY
-      } : {}
-    });
-    
-    if (!response.ok) {
-      throw new Error(`HTTP error: ${response.status}`);
-    }
-    
-    const data = await response.json();
-    return NextResponse.json(data);
+    const data = await helixRequest(requestPath);
+    return jsonResponse(request, data);
     
   } catch (error) {
-    console.error('Error with node-connections request:', error);
-    
-    const errorResponse = {
-      error: `Request failed: ${error instanceof Error ? error.message : 'Unknown error'}`,
-      ...createNodeConnectionsErrorData()
-    };
-    
-    return NextResponse.json(errorResponse);
+    logger.error('Error with node-connections request:', error);
+    return toApiError(error, 'node-connections').toResponse(request);
   }
-}
+});
@@ -1,68 +1,45 @@
-import { NextRequest, NextResponse } from 'next/server';
+import { NextRequest } from 'next/server';
+import { helixRequest, withQueryParams } from '@/lib/helix';
+import { resolveMaxLimit, validateLimit } from '@/lib/limits';
+import { toApiError } from '@/lib/api-errors';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { sortAndFilterNulls } from '@/lib/query';
+import { withStats } from '@/lib/stats';
 
-const MAX_LIMIT = 300;
-
-const host = process.env.DOCKER_HOST_INTERNAL || process.env.HELIX_HOST || 'localhost';
-const port = process.env.HELIX_PORT || 6969;
-const cloudUrl = process.env.HELIX_CLOUD_URL;
-
-const helixUrl = cloudUrl ? cloudUrl : `http://${host}:${port}`;
-
-function validateLimit(limit?: string): number | undefined {
-  if (!limit) return undefined;
-  const numLimit = parseInt(limit, 10);
-  return isNaN(numLimit) ? undefined : Math.min(numLimit, MAX_LIMIT);
-}
-
-function createDefaultErrorData() {
-  return {
-    nodes: [],
-    edges: [],
-    vectors: []
-  };
-}
-
-export async function GET(request: NextRequest) {
+export const GET = withStats(async function GET(request: NextRequest) {
   try {
     const url = new URL(request.url);
     const limitParam = url.searchParams.get('limit');
-    const nodeLabel = url.searchParams.get('node_label');
+    // Accept both repeated (`?node_labels=User&node_labels=Post`) and comma-separated labels
+    const nodeLabels = url.searchParams.getAll('node_labels')
+      .flatMap(labels => labels.split(','))
+      .map(label => label.trim())
+      .filter(label => label.length > 0);
+    const stripNulls = url.searchParams.get('strip_nulls') === 'true';
     
     // Build the request URL for the HelixDB instance
     const queryParams = new URLSearchParams();
     
-    const limit = validateLimit(limitParam || undefined);
+    const limit = validateLimit(limitParam || undefined, resolveMaxLimit(request));
     if (limit) {
       queryParams.append('limit', limit.toString());
     }
     
-    if (nodeLabel) {
-      queryParams.append('node_label', nodeLabel);
+    for (const label of nodeLabels) {
+      queryParams.append('node_labels', label);
     }
     
-    const requestUrl = `${helixUrl}/nodes-edges${queryParams.toString() ? '?' + queryParams.toString() : ''}`;
+    const requestPath = withQueryParams('nodes-edges', queryParams);
     
     // Make direct HTTP request to the HelixDB instance
-    const response = await fetch(requestUrl, {
-      headers: process.env.HELIX_API_KEY ? {
-        'x-api-key': process.env.HELIX_API_KEY
-      } : {}
-    });
-    
-    if (!response.ok) {
-      throw new Error(`HTTP error: ${response.status}`);
-    }
-    
-    const data = await response.json();
+    const data = await helixRequest(requestPath);
 
     
-    return NextResponse.json(data);
+    return jsonResponse(request, stripNulls ? sortAndFilterNulls(data) : data);
     
   } catch (error) {
-    console.error('Error with nodes-edges request:', error);
-    return NextResponse.json({
-      error: `Request failed: ${error instanceof Error ? error.message : 'Unknown error'}`,
-      data: createDefaultErrorData()
-    });
+    logger.error('Error with nodes-edges request:', error);
+    return toApiError(error, 'nodes-edges').toResponse(request);
   }
-}
+});
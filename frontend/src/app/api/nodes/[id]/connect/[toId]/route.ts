@@ -0,0 +1,65 @@
+import { NextRequest } from 'next/server';
+import { ApiError, toApiError } from '@/lib/api-errors';
+import { executeQuery, findCreateEdgeQuery } from '@/lib/query';
+import { checkJsonContentType, readBodyText } from '@/lib/request-body';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+interface ConnectBody {
+  edge_type?: string;
+  properties?: Record<string, unknown>;
+}
+
+// Create an edge between two existing nodes through the schema's create-edge query
+export const POST = withStats(async function POST(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string; toId: string }> }
+) {
+  const { id, toId } = await params;
+
+  let body: ConnectBody;
+  try {
+    const bodyText = await readBodyText(request);
+    const contentTypeError = checkJsonContentType(request, bodyText);
+    if (contentTypeError) {
+      throw contentTypeError;
+    }
+    body = JSON.parse(bodyText);
+  } catch (error) {
+    if (error instanceof ApiError) {
+      return error.toResponse(request);
+    }
+    return ApiError.invalidQuery('Request body must be valid JSON').toResponse(request);
+  }
+
+  if (!body?.edge_type || typeof body.edge_type !== 'string') {
+    return ApiError.missingParameter('edge_type').toResponse(request);
+  }
+
+  const properties = body.properties;
+  if (properties !== undefined && (properties === null || typeof properties !== 'object' || Array.isArray(properties))) {
+    return ApiError.invalidQuery('properties must be an object').toResponse(request);
+  }
+
+  try {
+    const query = await findCreateEdgeQuery(body.edge_type);
+    if (!query) {
+      return ApiError.notFound(`No query found to create '${body.edge_type}' edges`).toResponse(request);
+    }
+
+    // The first two ID parameters receive the source and target node IDs
+    const queryParams: Record<string, unknown> = { ...body.properties };
+    const idParams = Object.entries(query.parameters || {})
+      .filter(([, type]) => type === 'ID')
+      .map(([name]) => name);
+    queryParams[idParams[0] ?? 'from_id'] = id;
+    queryParams[idParams[1] ?? 'to_id'] = toId;
+
+    const data = await executeQuery(query.name, queryParams);
+    return jsonResponse(request, data, { status: 201 });
+  } catch (error) {
+    logger.error(`Error connecting node '${id}' to '${toId}':`, error);
+    return toApiError(error, body.edge_type).toResponse(request);
+  }
+});
@@ -0,0 +1,21 @@
+import { NextRequest } from 'next/server';
+import { helixHead } from '@/lib/helix';
+import { toApiError } from '@/lib/api-errors';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  const { id } = await params;
+
+  try {
+    const exists = await helixHead(`node-details?id=${encodeURIComponent(id)}`);
+    return jsonResponse(request, { exists }, { status: exists ? 200 : 404 });
+  } catch (error) {
+    logger.error(`Error checking existence of node '${id}':`, error);
+    return toApiError(error, 'node-details').toResponse(request);
+  }
+});
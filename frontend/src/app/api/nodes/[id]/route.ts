@@ -0,0 +1,25 @@
+import { NextRequest } from 'next/server';
+import { HelixError, helixRequest } from '@/lib/helix';
+import { ApiError, toApiError } from '@/lib/api-errors';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+// Path-parameter form of `/api/node-details?id=<id>`
+export const GET = withStats(async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  const { id } = await params;
+
+  try {
+    const data = await helixRequest(`node-details?id=${encodeURIComponent(id)}`);
+    return jsonResponse(request, data);
+  } catch (error) {
+    if (error instanceof HelixError && error.status === 404) {
+      return ApiError.nodeNotFound(id).toResponse(request);
+    }
+    logger.error(`Error fetching node '${id}':`, error);
+    return toApiError(error, 'node-details').toResponse(request);
+  }
+});
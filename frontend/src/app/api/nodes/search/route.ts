@@ -0,0 +1,40 @@
+import { NextRequest } from 'next/server';
+import { helixRequest, withQueryParams } from '@/lib/helix';
+import { resolveMaxLimit, validateLimit } from '@/lib/limits';
+import { ApiError, toApiError } from '@/lib/api-errors';
+import { validateSearchTerm } from '@/lib/params';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(request: NextRequest) {
+  try {
+    const url = new URL(request.url);
+    const q = url.searchParams.get('q');
+    const label = url.searchParams.get('label');
+    const limitParam = url.searchParams.get('limit');
+
+    if (!q) {
+      return ApiError.missingParameter('q').toResponse(request);
+    }
+
+    // Build the request URL for the HelixDB instance
+    const queryParams = new URLSearchParams();
+    queryParams.append('q', validateSearchTerm(q));
+
+    if (label) {
+      queryParams.append('label', label);
+    }
+
+    const limit = validateLimit(limitParam || undefined, resolveMaxLimit(request));
+    if (limit) {
+      queryParams.append('limit', limit.toString());
+    }
+
+    const data = await helixRequest(withQueryParams('nodes-search', queryParams));
+    return jsonResponse(request, data);
+  } catch (error) {
+    logger.error('Error with nodes-search request:', error);
+    return toApiError(error, 'nodes-search').toResponse(request);
+  }
+});
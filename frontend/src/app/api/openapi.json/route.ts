@@ -0,0 +1,21 @@
+import { NextRequest } from 'next/server';
+import { fetchIntrospect } from '@/lib/introspect';
+import { toApiError } from '@/lib/api-errors';
+import { buildOpenApiDocument, mapQueryToEndpoint } from '@/lib/endpoints';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(request: NextRequest) {
+  try {
+    const introspectData = await fetchIntrospect();
+    const endpoints = introspectData && introspectData.queries
+      ? introspectData.queries.map(mapQueryToEndpoint)
+      : [];
+
+    return jsonResponse(request, buildOpenApiDocument(endpoints));
+  } catch (error) {
+    logger.error('Error building OpenAPI document:', error);
+    return toApiError(error, 'introspect').toResponse(request);
+  }
+});
@@ -0,0 +1,33 @@
+import { NextRequest } from 'next/server';
+import { ApiError, toApiError } from '@/lib/api-errors';
+import { determineHttpMethod } from '@/lib/endpoints';
+import { findIntrospectQuery, getQueryParamTypes } from '@/lib/query';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ queryName: string }> }
+) {
+  const { queryName } = await params;
+
+  try {
+    const query = await findIntrospectQuery(queryName);
+    if (!query) {
+      return ApiError.notFound(`Query '${queryName}' not found`).toResponse(request);
+    }
+
+    const parameters = await getQueryParamTypes(queryName);
+
+    return jsonResponse(request, {
+      query_name: queryName,
+      method: determineHttpMethod(queryName),
+      parameters,
+      endpoint: `/api/query/${queryName}`
+    });
+  } catch (error) {
+    logger.error(`Error explaining query '${queryName}':`, error);
+    return toApiError(error, 'introspect').toResponse(request);
+  }
+});
@@ -0,0 +1,27 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { clearQueryHistory, getQueryHistory } from '@/lib/query-history';
+import { jsonResponse } from '@/lib/response';
+import { withStats } from '@/lib/stats';
+
+const DEFAULT_HISTORY_LIMIT = 10;
+
+export const GET = withStats(async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ queryName: string }> }
+) {
+  const { queryName } = await params;
+
+  const limit = parseInt(request.nextUrl.searchParams.get('limit') || '', 10);
+
+  return jsonResponse(request, getQueryHistory(queryName, isNaN(limit) ? DEFAULT_HISTORY_LIMIT : limit));
+});
+
+export const DELETE = withStats(async function DELETE(
+  request: NextRequest,
+  { params }: { params: Promise<{ queryName: string }> }
+) {
+  const { queryName } = await params;
+
+  clearQueryHistory(queryName);
+  return new NextResponse(null, { status: 204 });
+});
@@ -1,199 +1,77 @@
-import { NextRequest, NextResponse } from 'next/server';
-import HelixDB from 'helix-ts';
-
-// Helper function to create HelixDB client
-function createHelixClient(): HelixDB {
-    const host = process.env.DOCKER_HOST_INTERNAL || process.env.HELIX_HOST || 'localhost';
-    const port = process.env.HELIX_PORT || 6969;
-    const cloudUrl = process.env.HELIX_CLOUD_URL;
-
-    if (cloudUrl) {
-        return new HelixDB(cloudUrl);
-    }
-
-    return new HelixDB(`http://${host}:${port}`);
-}
-
-// Helper function to make HTTP requests with optional API key
-async function makeHttpRequestWithAuth(url: string): Promise<any> {
-    const headers: HeadersInit = {};
-
-    if (process.env.HELIX_API_KEY) {
-        headers['x-api-key'] = process.env.HELIX_API_KEY;
-    }
-
-    const response = await fetch(url, { headers });
-
-    if (!response.ok) {
-        throw new Error(`HTTP error: ${response.status}`);
-    }
-
-    return response.json();
-}
-
-// Helper function to convert string values to appropriate types
-function convertStringToType(value: string, paramType: string): any {
-    switch (paramType) {
-        case 'String':
-        case 'ID':
-            return value;
-        case 'Date':
-            return value;
-        case 'Boolean':
-        case 'Bool':
-            if (typeof value === 'boolean') return value;
-            const lowerValue = value.toLowerCase().trim();
-            return lowerValue === 'true' || lowerValue === '1' || lowerValue === 'yes';
-        case 'I8':
-        case 'I16':
-        case 'I32':
-        case 'I64':
-        case 'U8':
-        case 'U16':
-        case 'U32':
-        case 'U64':
-        case 'U128':
-            const intVal = parseInt(value, 10);
-            return isNaN(intVal) ? 0 : intVal;
-        case 'F32':
-        case 'F64':
-            const floatVal = parseFloat(value);
-            return isNaN(floatVal) ? 0.0 : floatVal;
-        case 'Array(F64)':
-        case '[F64]':
-            try {
-                if (value.startsWith('[') && value.endsWith(']')) {
-                    return JSON.parse(value);
-                }
-                return value.split(',').map(v => {
-                    const num = parseFloat(v.trim());
-                    return isNaN(num) ? 0.0 : num;
-                });
-            } catch {
-                return value.split(',').map(v => {
-                    const num = parseFloat(v.trim());
-                    return isNaN(num) ? 0.0 : num;
-                });
-            }
-        default:
-            return value;
-    }
-}
-
-// Helper function to get parameter types from introspect data
-async function getQueryParamTypes(client: HelixDB, queryName: string): Promise<Record<string, string>> {
-    try {
-        const introspectUrl = `${client.url}/introspect`;
-        const introspectData = await makeHttpRequestWithAuth(introspectUrl);
-        const paramTypes: Record<string, string> = {};
-
-        if (introspectData && introspectData.queries) {
-            const query = introspectData.queries.find((q: any) => q.name === queryName);
-            if (query && query.parameters && typeof query.parameters === 'object') {
-                for (const [paramName, paramType] of Object.entries(query.parameters)) {
-                    if (typeof paramType === 'string') {
-                        paramTypes[paramName] = paramType;
-                    }
-                }
-            }
-        }
-
-        return paramTypes;
-    } catch (error) {
-        console.warn('Could not fetch parameter types:', error);
-        return {};
-    }
-}
-
-// Helper function to sort JSON objects (replicating Rust backend behavior)
-function sortJsonObject(value: any): any {
-    if (value && typeof value === 'object' && !Array.isArray(value)) {
-        const sorted: any = {};
-
-        // Separate numeric keys, id key, and other keys
-        const numericKeys: [string, any][] = [];
-        let idKey: [string, any] | null = null;
-        const otherKeys: [string, any][] = [];
-
-        for (const [key, val] of Object.entries(value)) {
-            const sortedVal = sortJsonObject(val);
-
-            if (/^\d+$/.test(key)) {
-                numericKeys.push([key, sortedVal]);
-            } else if (key === 'id') {
-                idKey = [key, sortedVal];
-            } else {
-                otherKeys.push([key, sortedVal]);
-            }
-        }
-
-        // Sort numeric keys by numeric value
-        numericKeys.sort(([a], [b]) => parseInt(a, 10) - parseInt(b, 10));
-
-        // Add in order: numeric keys, id key, other keys
-        for (const [k, v] of numericKeys) {
-            sorted[k] = v;
-        }
-        if (idKey) {
-            sorted[idKey[0]] = idKey[1];
-        }
-        for (const [k, v] of otherKeys) {
-            sorted[k] = v;
-        }
-
-        return sorted;
-    } else if (Array.isArray(value)) {
-        return value.map(sortJsonObject);
-    }
-
-    return value;
-}
+import { NextRequest } from 'next/server';
+import { toApiError } from '@/lib/api-errors';
+import { getRequestId } from '@/lib/request-id';
+import { executeQuery, searchParamsToObject, stripNulls, takeControlParams, validateQueryStringParams, withMetadata } from '@/lib/query';
+import { recordQueryResult } from '@/lib/query-history';
+import { checkJsonContentType, readBodyText } from '@/lib/request-body';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { csvResponse, parseResponseFormat } from '@/lib/csv';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(
+    request: NextRequest,
+    { params }: { params: Promise<{ queryName: string }> }
+) {
+    const { queryName } = await params;
+    return await handleQueryExecution(request, queryName);
+});
 
-export async function GET(
+export const POST = withStats(async function POST(
     request: NextRequest,
     { params }: { params: Promise<{ queryName: string }> }
 ) {
     const { queryName } = await params;
     return await handleQueryExecution(request, queryName);
-}
+});
 
-export async function POST(
+export const PUT = withStats(async function PUT(
     request: NextRequest,
     { params }: { params: Promise<{ queryName: string }> }
 ) {
     const { queryName } = await params;
     return await handleQueryExecution(request, queryName);
-}
+});
 
-export async function PUT(
+export const PATCH = withStats(async function PATCH(
     request: NextRequest,
     { params }: { params: Promise<{ queryName: string }> }
 ) {
     const { queryName } = await params;
     return await handleQueryExecution(request, queryName);
-}
+});
 
-export async function DELETE(
+export const DELETE = withStats(async function DELETE(
     request: NextRequest,
     { params }: { params: Promise<{ queryName: string }> }
 ) {
     const { queryName } = await params;
     return await handleQueryExecution(request, queryName);
-}
+});
+
+const BODY_METHODS = ['POST', 'PUT', 'PATCH', 'DELETE'];
 
 async function handleQueryExecution(request: NextRequest, queryName: string) {
     try {
-        const client = createHelixClient();
-
         // Get query parameters from URL
         const url = new URL(request.url);
-        const queryParams = Object.fromEntries(url.searchParams.entries());
-
-        // Get body parameters for POST/PUT requests
+        const queryParams = searchParamsToObject(url.searchParams);
+        const controls = await takeControlParams(queryName, queryParams);
+        const shouldStripNulls = controls.strip_nulls === 'true';
+        const includeMetadata = controls.include_metadata === 'true';
+        const format = parseResponseFormat(controls.format);
+        await validateQueryStringParams(queryName, queryParams);
+
+        // Get body parameters for POST/PUT/PATCH requests, and DELETE requests carrying a filter body
         let bodyParams: any = {};
-        if (request.method === 'POST' || request.method === 'PUT') {
+        if (BODY_METHODS.includes(request.method)) {
+            const bodyText = await readBodyText(request);
+            const contentTypeError = checkJsonContentType(request, bodyText);
+            if (contentTypeError) {
+                throw contentTypeError;
+            }
             try {
-                const body = await request.json();
+                const body = JSON.parse(bodyText);
                 if (typeof body === 'object' && body !== null) {
                     bodyParams = body;
                 }
@@ -205,33 +83,24 @@ async function handleQueryExecution(request: NextRequest, queryName: string) {
         // Merge parameters (body parameters take precedence)
         const allParams = { ...queryParams, ...bodyParams };
 
-        // Get parameter types and convert values appropriately
-        const paramTypes = await getQueryParamTypes(client, queryName);
-        const convertedParams: any = {};
+        const start = Date.now();
+        let result = await executeQuery(queryName, allParams);
+        const durationMs = Date.now() - start;
+        logger.trace(`[${getRequestId(request)}] Executed query '${queryName}' in ${durationMs}ms`);
+        recordQueryResult(queryName, result);
 
-        for (const [key, value] of Object.entries(allParams)) {
-            if (typeof value === 'string' && paramTypes[key]) {
-                convertedParams[key] = convertStringToType(value, paramTypes[key]);
-            } else {
-                convertedParams[key] = value;
-            }
+        if (shouldStripNulls) {
+            result = stripNulls(result);
         }
 
-        // Execute the query
-        const result = await client.query(queryName, convertedParams);
-
-        // Sort the result to match Rust backend behavior
-        const sortedResult = sortJsonObject(result);
+        if (format === 'csv') {
+            return csvResponse(result);
+        }
 
-        return NextResponse.json(sortedResult);
+        return jsonResponse(request, includeMetadata ? withMetadata(result, queryName, durationMs) : result);
     } catch (error) {
-        console.error(`Error executing query '${queryName}':`, error);
-        return NextResponse.json(
-            {
-                error: `Failed to execute query: ${error instanceof Error ? error.message : 'Unknown error'}`,
-                query: queryName
-            },
-            { status: 500 }
-        );
+        const apiError = toApiError(error, queryName);
+        logger.error(`[${getRequestId(request)}] Error executing query '${queryName}': ${apiError.debugMessage()}`, error);
+        return apiError.toResponse(request);
     }
 }
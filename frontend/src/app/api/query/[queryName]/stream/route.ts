@@ -0,0 +1,136 @@
+import { NextRequest } from 'next/server';
+import { ApiError, toApiError } from '@/lib/api-errors';
+import { validateInterval } from '@/lib/limits';
+import { executeQuery, searchParamsToObject, takeControlParams, validateQueryStringParams } from '@/lib/query';
+import { checkJsonContentType, readBodyText } from '@/lib/request-body';
+import { jsonResponse } from '@/lib/response';
+import { registerStream, unregisterStream, updateStreamInterval } from '@/lib/stream-registry';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ queryName: string }> }
+) {
+  const { queryName } = await params;
+
+  const url = new URL(request.url);
+  const queryParams = searchParamsToObject(url.searchParams);
+  let intervalMs: number;
+  try {
+    const controls = await takeControlParams(queryName, queryParams);
+    intervalMs = validateInterval(controls.interval_ms);
+    await validateQueryStringParams(queryName, queryParams);
+  } catch (error) {
+    return toApiError(error, queryName).toResponse(request);
+  }
+
+  const streamId = crypto.randomUUID();
+  const encoder = new TextEncoder();
+  let timer: ReturnType<typeof setInterval> | undefined;
+  let closed = false;
+  let polling = false;
+
+  const stream = new ReadableStream({
+    start(controller) {
+      const close = () => {
+        if (closed) return;
+        closed = true;
+        clearInterval(timer);
+        unregisterStream(streamId);
+        controller.close();
+      };
+
+      const poll = async () => {
+        // Skip this tick if the previous query is still running
+        if (polling) return;
+        polling = true;
+
+        let frame: string;
+        try {
+          const result = await executeQuery(queryName, queryParams);
+          frame = `event: data\ndata: ${JSON.stringify(result)}\n\n`;
+        } catch (error) {
+          logger.error(`Error streaming query '${queryName}':`, error);
+          const problem = toApiError(error, queryName).toProblemDetails(request.nextUrl.pathname);
+          frame = `event: error\ndata: ${JSON.stringify(problem)}\n\n`;
+        } finally {
+          polling = false;
+        }
+        if (!closed) {
+          controller.enqueue(encoder.encode(frame));
+        }
+      };
+
+      registerStream(streamId, {
+        queryName,
+        setIntervalMs: (newIntervalMs: number) => {
+          if (closed) return;
+          intervalMs = newIntervalMs;
+          clearInterval(timer);
+          timer = setInterval(poll, intervalMs);
+        }
+      });
+
+      // Stop polling as soon as the client disconnects
+      request.signal.addEventListener('abort', close);
+
+      // EventSource clients cannot read response headers, so announce the ID for PATCH in the stream too
+      controller.enqueue(encoder.encode(`event: stream\ndata: ${JSON.stringify({ stream_id: streamId, interval_ms: intervalMs })}\n\n`));
+
+      poll();
+      timer = setInterval(poll, intervalMs);
+    },
+    cancel() {
+      closed = true;
+      clearInterval(timer);
+      unregisterStream(streamId);
+    }
+  });
+
+  return new Response(stream, {
+    headers: {
+      'Content-Type': 'text/event-stream',
+      'Cache-Control': 'no-cache',
+      'Connection': 'keep-alive',
+      'X-Stream-Id': streamId
+    }
+  });
+});
+
+// Change the polling interval of an open stream: `{ "stream_id": "...", "interval_ms": 10000 }`
+export const PATCH = withStats(async function PATCH(
+  request: NextRequest,
+  { params }: { params: Promise<{ queryName: string }> }
+) {
+  const { queryName } = await params;
+
+  let body: any;
+  try {
+    const bodyText = await readBodyText(request);
+    const contentTypeError = checkJsonContentType(request, bodyText);
+    if (contentTypeError) {
+      throw contentTypeError;
+    }
+    body = JSON.parse(bodyText);
+  } catch (error) {
+    if (error instanceof ApiError) {
+      return error.toResponse(request);
+    }
+    return ApiError.invalidQuery('Request body must be valid JSON').toResponse(request);
+  }
+
+  if (typeof body?.stream_id !== 'string' || !body.stream_id) {
+    return ApiError.missingParameter('stream_id').toResponse(request);
+  }
+  if (typeof body.interval_ms !== 'number') {
+    return ApiError.missingParameter('interval_ms').toResponse(request);
+  }
+
+  const intervalMs = validateInterval(body.interval_ms);
+  if (!updateStreamInterval(body.stream_id, queryName, intervalMs)) {
+    return ApiError.notFound(`Stream '${body.stream_id}' not found for query '${queryName}'`).toResponse(request);
+  }
+
+  return jsonResponse(request, { stream_id: body.stream_id, interval_ms: intervalMs });
+});
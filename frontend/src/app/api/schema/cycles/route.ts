@@ -0,0 +1,19 @@
+import { NextRequest } from 'next/server';
+import { toApiError } from '@/lib/api-errors';
+import { fetchSchema, findCycles, isSelfReferential } from '@/lib/schema';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(request: NextRequest) {
+  try {
+    const schema = await fetchSchema();
+    return jsonResponse(request, {
+      cycles: findCycles(schema),
+      self_referential: schema.edges.filter(isSelfReferential).map(edge => edge.name)
+    });
+  } catch (error) {
+    logger.error('Error detecting schema cycles:', error);
+    return toApiError(error, 'introspect').toResponse(request);
+  }
+});
@@ -0,0 +1,19 @@
+import { NextRequest } from 'next/server';
+import { toApiError } from '@/lib/api-errors';
+import { fetchSchema, toGraphvizDot } from '@/lib/schema';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(request: NextRequest) {
+  const directed = request.nextUrl.searchParams.get('directed') !== 'false';
+
+  try {
+    const schema = await fetchSchema();
+    return new Response(toGraphvizDot(schema, directed), {
+      headers: { 'Content-Type': 'text/plain; charset=utf-8' }
+    });
+  } catch (error) {
+    logger.error('Error building Graphviz DOT graph:', error);
+    return toApiError(error, 'introspect').toResponse(request);
+  }
+});
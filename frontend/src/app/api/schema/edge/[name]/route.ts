@@ -0,0 +1,27 @@
+import { NextRequest } from 'next/server';
+import { ApiError, toApiError } from '@/lib/api-errors';
+import { fetchSchema, getEdge } from '@/lib/schema';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ name: string }> }
+) {
+  const { name } = await params;
+
+  try {
+    const schema = await fetchSchema();
+    const edge = getEdge(schema, name);
+
+    if (!edge) {
+      return ApiError.notFound(`Edge type '${name}' not found in schema`).toResponse(request);
+    }
+
+    return jsonResponse(request, edge);
+  } catch (error) {
+    logger.error(`Error fetching schema for edge '${name}':`, error);
+    return toApiError(error, 'introspect').toResponse(request);
+  }
+});
@@ -0,0 +1,17 @@
+import { NextRequest } from 'next/server';
+import { toApiError } from '@/lib/api-errors';
+import { fetchSchema, toMermaidDiagram } from '@/lib/schema';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(request: NextRequest) {
+  try {
+    const schema = await fetchSchema();
+    return new Response(toMermaidDiagram(schema), {
+      headers: { 'Content-Type': 'text/plain; charset=utf-8' }
+    });
+  } catch (error) {
+    logger.error('Error building Mermaid diagram:', error);
+    return toApiError(error, 'introspect').toResponse(request);
+  }
+});
@@ -0,0 +1,45 @@
+import { NextRequest } from 'next/server';
+import { ApiError, toApiError } from '@/lib/api-errors';
+import { entityKind, fetchSchema, findPath } from '@/lib/schema';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+const DEFAULT_MAX_DEPTH = 3;
+const MAX_PATH_DEPTH = 5;
+
+export const GET = withStats(async function GET(request: NextRequest) {
+  const url = new URL(request.url);
+  const from = url.searchParams.get('from');
+  const to = url.searchParams.get('to');
+
+  if (!from) {
+    return ApiError.missingParameter('from').toResponse(request);
+  }
+  if (!to) {
+    return ApiError.missingParameter('to').toResponse(request);
+  }
+
+  const maxDepthParam = parseInt(url.searchParams.get('max_depth') || '', 10);
+  const maxDepth = isNaN(maxDepthParam) ? DEFAULT_MAX_DEPTH : Math.min(Math.max(maxDepthParam, 1), MAX_PATH_DEPTH);
+
+  try {
+    const schema = await fetchSchema();
+
+    for (const name of [from, to]) {
+      // Paths only run between node types, so an edge or vector name would just yield no paths
+      const kind = entityKind(schema, name);
+      if (kind === undefined) {
+        return ApiError.invalidQuery(`Node type '${name}' not found in schema`).toResponse(request);
+      }
+      if (kind !== 'node') {
+        return ApiError.invalidQuery(`'${name}' is ${kind === 'edge' ? 'an edge' : 'a vector'} type; paths can only be found between node types`).toResponse(request);
+      }
+    }
+
+    return jsonResponse(request, findPath(schema, from, to, maxDepth));
+  } catch (error) {
+    logger.error(`Error finding schema path from '${from}' to '${to}':`, error);
+    return toApiError(error, 'introspect').toResponse(request);
+  }
+});
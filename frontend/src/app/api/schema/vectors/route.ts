@@ -0,0 +1,16 @@
+import { NextRequest } from 'next/server';
+import { toApiError } from '@/lib/api-errors';
+import { fetchSchema } from '@/lib/schema';
+import { jsonResponse } from '@/lib/response';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(request: NextRequest) {
+  try {
+    const schema = await fetchSchema();
+    return jsonResponse(request, schema.vectors);
+  } catch (error) {
+    logger.error('Error fetching schema vectors:', error);
+    return toApiError(error, 'introspect').toResponse(request);
+  }
+});
@@ -0,0 +1,127 @@
+import { NextRequest } from 'next/server';
+import { toApiError } from '@/lib/api-errors';
+import { validateInterval } from '@/lib/limits';
+import { clearParamTypesCache } from '@/lib/query';
+import { fetchSchema } from '@/lib/schema';
+import { logger } from '@/lib/logger';
+import { withStats } from '@/lib/stats';
+
+interface Subscriber {
+  intervalMs: number;
+  send: (frame: string) => void;
+}
+
+// One introspect poller is shared by every connected client and fans each change out to all of them
+const subscribers = new Set<Subscriber>();
+let timer: ReturnType<typeof setInterval> | undefined;
+let timerIntervalMs: number | undefined;
+let polling = false;
+let lastSchema: string | undefined;
+
+function broadcast(frame: string) {
+  for (const subscriber of subscribers) {
+    subscriber.send(frame);
+  }
+}
+
+async function poll(instance: string) {
+  // Skip this tick if the previous introspect is still running
+  if (polling) return;
+  polling = true;
+
+  try {
+    const schema = JSON.stringify(await fetchSchema());
+    if (schema !== lastSchema) {
+      // A redeployed schema may change query parameter types too
+      if (lastSchema !== undefined) {
+        clearParamTypesCache();
+      }
+      lastSchema = schema;
+      broadcast(`event: schema_update\ndata: ${schema}\n\n`);
+    }
+  } catch (error) {
+    logger.error('Error watching schema:', error);
+    const problem = toApiError(error, 'introspect').toProblemDetails(instance);
+    broadcast(`event: error\ndata: ${JSON.stringify(problem)}\n\n`);
+  } finally {
+    polling = false;
+  }
+}
+
+// Poll at the shortest interval any connected client asked for, and stop once the last one leaves
+function reschedule(instance: string) {
+  if (subscribers.size === 0) {
+    clearInterval(timer);
+    timer = undefined;
+    timerIntervalMs = undefined;
+    lastSchema = undefined;
+    return;
+  }
+
+  const intervalMs = Math.min(...Array.from(subscribers, subscriber => subscriber.intervalMs));
+  if (intervalMs === timerIntervalMs) return;
+  clearInterval(timer);
+  timerIntervalMs = intervalMs;
+  timer = setInterval(() => poll(instance), intervalMs);
+}
+
+// Emit a `schema_update` event with the current schema on connect and whenever it changes
+export const GET = withStats(async function GET(request: NextRequest) {
+  const intervalMs = validateInterval(request.nextUrl.searchParams.get('interval_ms') || undefined);
+  const instance = request.nextUrl.pathname;
+
+  const encoder = new TextEncoder();
+  let subscriber: Subscriber | undefined;
+  let closed = false;
+
+  const unsubscribe = () => {
+    if (closed) return;
+    closed = true;
+    if (subscriber) {
+      subscribers.delete(subscriber);
+    }
+    reschedule(instance);
+  };
+
+  const stream = new ReadableStream({
+    start(controller) {
+      subscriber = {
+        intervalMs,
+        send: (frame: string) => {
+          if (!closed) {
+            controller.enqueue(encoder.encode(frame));
+          }
+        }
+      };
+
+      const first = subscribers.size === 0;
+      subscribers.add(subscriber);
+      reschedule(instance);
+
+      // New clients start from the current schema: the cached one if a poller is running, else a fresh poll
+      if (first || lastSchema === undefined) {
+        poll(instance);
+      } else {
+        subscriber.send(`event: schema_update\ndata: ${lastSchema}\n\n`);
+      }
+
+      // Stop polling for this client as soon as it disconnects
+      request.signal.addEventListener('abort', () => {
+        if (closed) return;
+        unsubscribe();
+        controller.close();
+      });
+    },
+    cancel() {
+      unsubscribe();
+    }
+  });
+
+  return new Response(stream, {
+    headers: {
+      'Content-Type': 'text/event-stream',
+      'Cache-Control': 'no-cache',
+      'Connection': 'keep-alive'
+    }
+  });
+});
@@ -0,0 +1,7 @@
+import { NextRequest } from 'next/server';
+import { jsonResponse } from '@/lib/response';
+import { getStats, withStats } from '@/lib/stats';
+
+export const GET = withStats(async function GET(request: NextRequest) {
+  return jsonResponse(request, getStats());
+});
@@ -0,0 +1,187 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { HelixError } from './helix';
+import { logger } from './logger';
+import { getRequestId } from './request-id';
+import { envelope, wantsEnvelope } from './response';
+
+const PROBLEM_TYPE_BASE_URI = 'https://helixdb.io/errors';
+
+export type ApiErrorKind = 'InvalidQuery' | 'NotFound' | 'TooLarge' | 'Internal' | 'RateLimited' | 'Timeout' | 'DatabaseError' | 'UnsupportedMediaType';
+
+const TITLES: Record<ApiErrorKind, string> = {
+  InvalidQuery: 'Invalid query',
+  NotFound: 'Not found',
+  TooLarge: 'Payload too large',
+  Internal: 'Internal server error',
+  RateLimited: 'Rate limited',
+  Timeout: 'Request timed out',
+  DatabaseError: 'Database unavailable',
+  UnsupportedMediaType: 'Unsupported media type'
+};
+
+// RFC 7807 Problem Details body
+export interface ProblemDetails {
+  type: string;
+  title: string;
+  status: number;
+  detail: string;
+  instance?: string;
+  request_id?: string;
+}
+
+// User-facing sentence for each error kind; technical details stay in `debugMessage()`
+function friendlyMessage(kind: ApiErrorKind, detail: string): string {
+  switch (kind) {
+    case 'InvalidQuery':
+      return `The request parameters are invalid: ${detail}`;
+    case 'NotFound':
+      // Details already say what was not found, e.g. "Node '42' not found"
+      return detail;
+    case 'TooLarge':
+      return `The request is too large: ${detail}`;
+    case 'RateLimited':
+      return 'Too many requests. Please wait a moment and try again.';
+    case 'Timeout':
+      return 'The request took too long to complete. Please try again.';
+    case 'DatabaseError':
+      return 'Unable to reach the database. Please check your HelixDB connection.';
+    case 'UnsupportedMediaType':
+      return `The request body format is not supported: ${detail}`;
+    case 'Internal':
+      return 'Something went wrong while processing the request.';
+  }
+}
+
+export class ApiError extends Error {
+  constructor(
+    readonly kind: ApiErrorKind,
+    readonly status: number,
+    readonly detail: string,
+    readonly retryAfterSecs?: number
+  ) {
+    super(friendlyMessage(kind, detail));
+    this.name = 'ApiError';
+  }
+
+  // Technical description for server-side logs
+  debugMessage(): string {
+    return `${this.kind} (${this.status}): ${this.detail}`;
+  }
+
+  static invalidQuery(message: string): ApiError {
+    return new ApiError('InvalidQuery', 400, message);
+  }
+
+  static missingParameter(name: string): ApiError {
+    return ApiError.invalidQuery(`${name} parameter is required`);
+  }
+
+  static notFound(message: string): ApiError {
+    return new ApiError('NotFound', 404, message);
+  }
+
+  static nodeNotFound(id: string): ApiError {
+    return ApiError.notFound(`Node '${id}' not found`);
+  }
+
+  static tooLarge(actualBytes: number, maxBytes: number): ApiError {
+    return new ApiError('TooLarge', 413, `Request body of ${actualBytes} bytes exceeds the limit of ${maxBytes} bytes`);
+  }
+
+  static internal(message: string): ApiError {
+    return new ApiError('Internal', 500, message);
+  }
+
+  static rateLimited(retryAfterSecs?: number, message = 'Rate limited by HelixDB'): ApiError {
+    return new ApiError('RateLimited', 429, message, retryAfterSecs);
+  }
+
+  static queryFailed(context: string, error: unknown): ApiError {
+    return ApiError.internal(`Request failed for '${context}': ${error instanceof Error ? error.message : 'Unknown error'}`);
+  }
+
+  static timeout(queryName: string): ApiError {
+    return new ApiError('Timeout', 504, `Request timed out: ${queryName}`);
+  }
+
+  static unsupportedMediaType(message: string): ApiError {
+    return new ApiError('UnsupportedMediaType', 415, message);
+  }
+
+  static databaseError(message: string): ApiError {
+    return new ApiError('DatabaseError', 503, message);
+  }
+
+  // Map a failed HelixDB request onto the matching API error, `context` being the query name or endpoint
+  static fromBackendError(error: HelixError, context: string): ApiError {
+    if (error.isDeserialize) {
+      logger.error(`Invalid JSON from HelixDB endpoint '${error.endpoint}': ${error.rawBody}`);
+    }
+    if (error.timedOut) {
+      return ApiError.timeout(context);
+    }
+    // No status means HelixDB was never reached, e.g. connection refused
+    if (error.status === undefined) {
+      return ApiError.databaseError(`HelixDB unreachable for '${context}': ${error.message}`);
+    }
+
+    switch (error.status) {
+      case 404:
+        return ApiError.notFound(`'${context}' not found`);
+      case 429:
+        return ApiError.rateLimited(error.retryAfterSecs);
+      case 503:
+        return ApiError.databaseError(`HelixDB unavailable for '${context}': ${error.message}`);
+      default:
+        return ApiError.queryFailed(context, error);
+    }
+  }
+
+  toProblemDetails(instance?: string, requestId?: string): ProblemDetails {
+    const problem: ProblemDetails = {
+      type: `${PROBLEM_TYPE_BASE_URI}/${this.kind}`,
+      title: TITLES[this.kind],
+      status: this.status,
+      detail: this.message
+    };
+    if (instance) {
+      problem.instance = instance;
+    }
+    if (requestId) {
+      problem.request_id = requestId;
+    }
+    return problem;
+  }
+
+  toResponse(request?: NextRequest): NextResponse {
+    const headers: Record<string, string> = {};
+    if (this.kind === 'RateLimited' && this.retryAfterSecs !== undefined) {
+      headers['Retry-After'] = this.retryAfterSecs.toString();
+    }
+
+    if (request && wantsEnvelope(request)) {
+      const body = envelope(request, null, { code: this.kind, message: this.message });
+      return NextResponse.json(body, { status: this.status, headers });
+    }
+
+    headers['Content-Type'] = 'application/problem+json';
+    const body = request
+      ? this.toProblemDetails(request.nextUrl.pathname, getRequestId(request))
+      : this.toProblemDetails();
+
+    return NextResponse.json(body, { status: this.status, headers });
+  }
+}
+
+// Convert any thrown value into an ApiError, `context` being the query name or endpoint
+export function toApiError(error: unknown, context: string): ApiError {
+  if (error instanceof ApiError) {
+    return error;
+  }
+
+  if (error instanceof HelixError) {
+    return ApiError.fromBackendError(error, context);
+  }
+
+  return ApiError.queryFailed(context, error);
+}
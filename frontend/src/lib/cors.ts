@@ -0,0 +1,32 @@
+const ALLOWED_METHODS = 'GET, POST, PUT, PATCH, DELETE, OPTIONS';
+
+// Parse the comma-separated HELIX_CORS_ORIGINS value, `*` allowing any origin
+export function parseCorsOrigins(origins?: string): string[] {
+  if (!origins) return [];
+  return origins
+    .split(',')
+    .map(origin => origin.trim())
+    .filter(origin => origin.length > 0);
+}
+
+// CORS headers for a request from `origin`, or null if the origin is not allowed
+export function buildCorsHeaders(origin: string | null, allowedOrigins: string[]): Record<string, string> | null {
+  if (!origin || allowedOrigins.length === 0) return null;
+
+  if (allowedOrigins.includes('*')) {
+    return {
+      'Access-Control-Allow-Origin': '*',
+      'Access-Control-Allow-Methods': ALLOWED_METHODS,
+      'Access-Control-Allow-Headers': '*'
+    };
+  }
+
+  if (!allowedOrigins.includes(origin)) return null;
+
+  return {
+    'Access-Control-Allow-Origin': origin,
+    'Access-Control-Allow-Methods': ALLOWED_METHODS,
+    'Access-Control-Allow-Headers': '*',
+    'Vary': 'Origin'
+  };
+}
@@ -0,0 +1,75 @@
+import { NextResponse } from 'next/server';
+import { MAX_SORT_DEPTH } from './query';
+
+export type ResponseFormat = 'json' | 'csv';
+
+export function parseResponseFormat(value: string | null | undefined): ResponseFormat {
+  return value?.toLowerCase() === 'csv' ? 'csv' : 'json';
+}
+
+// Objects nested deeper than this are written as a single JSON field instead of being flattened further
+const MAX_FLATTEN_DEPTH = MAX_SORT_DEPTH;
+
+// Flatten nested objects into dot-notation keys, e.g. `{ address: { city } }` becomes `address.city`
+function flattenObject(value: Record<string, unknown>, prefix = '', out: Record<string, unknown> = {}, depth = 0): Record<string, unknown> {
+  for (const [key, entry] of Object.entries(value)) {
+    const path = prefix ? `${prefix}.${key}` : key;
+    if (entry && typeof entry === 'object' && !Array.isArray(entry) && depth < MAX_FLATTEN_DEPTH) {
+      flattenObject(entry as Record<string, unknown>, path, out, depth + 1);
+    } else {
+      out[path] = entry;
+    }
+  }
+  return out;
+}
+
+function escapeCsvField(value: unknown): string {
+  if (value === null || value === undefined) {
+    return '';
+  }
+  const text = typeof value === 'object' ? JSON.stringify(value) : String(value);
+  return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text;
+}
+
+export function jsonArrayToCsv(headers: string[], rows: unknown[]): string {
+  const lines = [headers.map(escapeCsvField).join(',')];
+
+  for (const row of rows) {
+    const flat = row && typeof row === 'object' && !Array.isArray(row)
+      ? flattenObject(row as Record<string, unknown>)
+      : { value: row };
+    lines.push(headers.map(header => escapeCsvField(flat[header])).join(','));
+  }
+
+  return lines.join('\r\n') + '\r\n';
+}
+
+// Convert a query result to CSV; a non-array result becomes a single row
+export function toCsv(result: unknown): string {
+  const rows = Array.isArray(result) ? result : [result];
+
+  const headers: string[] = [];
+  const seen = new Set<string>();
+  for (const row of rows) {
+    const keys = row && typeof row === 'object' && !Array.isArray(row)
+      ? Object.keys(flattenObject(row as Record<string, unknown>))
+      : ['value'];
+    for (const key of keys) {
+      if (!seen.has(key)) {
+        seen.add(key);
+        headers.push(key);
+      }
+    }
+  }
+
+  return jsonArrayToCsv(headers, rows);
+}
+
+export function csvResponse(result: unknown, filename = 'query_result.csv'): NextResponse {
+  return new NextResponse(toCsv(result), {
+    headers: {
+      'Content-Type': 'text/csv; charset=utf-8',
+      'Content-Disposition': `attachment; filename="${filename}"`
+    }
+  });
+}
@@ -0,0 +1,272 @@
+export interface QueryParameter {
+  name: string;
+  param_type: string;
+}
+
+export interface ApiEndpointInfo {
+  path: string;
+  method: string;
+  query_name: string;
+  parameters: QueryParameter[];
+  description?: string;
+  return_type?: string;
+  curl_example?: string;
+}
+
+const READ_PREFIXES = ['get', 'find', 'list', 'search', 'fetch', 'count', 'check'];
+const CREATE_PREFIXES = ['create', 'add', 'assign'];
+const UPDATE_PREFIXES = ['update'];
+const PATCH_PREFIXES = ['patch'];
+const DELETE_PREFIXES = ['delete', 'remove'];
+
+function hasPrefix(queryName: string, prefixes: string[]): boolean {
+  return prefixes.some(prefix => queryName.startsWith(prefix));
+}
+
+// Helper function to determine HTTP method based on query name
+export function determineHttpMethod(queryName: string): string {
+  // Read prefixes are matched first so they never fall into a write mapping
+  if (hasPrefix(queryName, READ_PREFIXES)) {
+    return 'GET';
+  } else if (hasPrefix(queryName, CREATE_PREFIXES)) {
+    return 'POST';
+  } else if (hasPrefix(queryName, UPDATE_PREFIXES)) {
+    return 'PUT';
+  } else if (hasPrefix(queryName, PATCH_PREFIXES)) {
+    return 'PATCH';
+  } else if (hasPrefix(queryName, DELETE_PREFIXES)) {
+    return 'DELETE';
+  } else {
+    return 'GET';
+  }
+}
+
+// Normalize HelixDB type aliases so the dashboard sees a single name per type
+export function normalizeParamType(paramType: string): string {
+  if (paramType === 'Boolean' || paramType === 'Bool') {
+    return 'bool';
+  }
+  return paramType;
+}
+
+// Helper function to map query to endpoint format
+export function mapQueryToEndpoint(query: any): ApiEndpointInfo {
+  const parameters = [];
+  
+  if (query.parameters && typeof query.parameters === 'object') {
+    for (const [name, type] of Object.entries(query.parameters)) {
+      parameters.push({
+        name,
+        param_type: typeof type === 'string' ? normalizeParamType(type) : 'String'
+      });
+    }
+  }
+
+  const method = determineHttpMethod(query.name);
+
+  const endpoint: ApiEndpointInfo = {
+    path: `/api/query/${query.name}`,
+    method,
+    query_name: query.name,
+    parameters
+  };
+
+  if (typeof query.description === 'string') {
+    endpoint.description = query.description;
+  }
+  if (typeof query.return_type === 'string') {
+    endpoint.return_type = query.return_type;
+  }
+
+  return endpoint;
+}
+
+function shellQuote(value: string): string {
+  return `'${value.replace(/'/g, `'\\''`)}'`;
+}
+
+// Placeholder values named after each parameter's type, used when the query has no example
+function placeholderParams(endpoint: ApiEndpointInfo): Record<string, unknown> {
+  const params: Record<string, unknown> = {};
+  for (const param of endpoint.parameters) {
+    params[param.name] = `<${param.param_type}>`;
+  }
+  return params;
+}
+
+// curl invocation for an endpoint: query-string parameters for GET/DELETE, a JSON body otherwise
+export function toCurlCommand(endpoint: ApiEndpointInfo, baseUrl: string, example?: Record<string, unknown>): string {
+  const params = example ?? placeholderParams(endpoint);
+  const url = shellQuote(`${baseUrl}${endpoint.path}`);
+
+  if (endpoint.method === 'POST' || endpoint.method === 'PUT' || endpoint.method === 'PATCH') {
+    return [
+      `curl -X ${endpoint.method} ${url}`,
+      `-H 'Content-Type: application/json'`,
+      `-d ${shellQuote(JSON.stringify(params))}`
+    ].join(' \\\n  ');
+  }
+
+  const parts = [endpoint.method === 'GET' ? `curl -G ${url}` : `curl -X ${endpoint.method} -G ${url}`];
+  for (const [name, value] of Object.entries(params)) {
+    const text = typeof value === 'string' ? value : JSON.stringify(value);
+    parts.push(`--data-urlencode ${shellQuote(`${name}=${text}`)}`);
+  }
+  return parts.join(' \\\n  ');
+}
+
+const INTEGER_FORMATS: Record<string, string> = { I32: 'int32', I64: 'int64' };
+
+// Element type of `Array(T)` or `[T]`
+function arrayElementType(helixType: string): string | undefined {
+  const match = /^Array\((.+)\)$/.exec(helixType) || /^\[(.+)\]$/.exec(helixType);
+  return match ? match[1].trim() : undefined;
+}
+
+// Value type of `Map<K, V>`
+function mapValueType(helixType: string): string | undefined {
+  const match = /^Map<\s*[^,]+?\s*,\s*(.+)>$/.exec(helixType);
+  return match ? match[1].trim() : undefined;
+}
+
+// JSON Schema for a HelixDB type, recursing into array and map element types
+export function toJsonSchemaType(helixType: string): Record<string, any> {
+  const elementType = arrayElementType(helixType);
+  if (elementType) {
+    return { type: 'array', items: toJsonSchemaType(elementType) };
+  }
+  const valueType = mapValueType(helixType);
+  if (valueType) {
+    return { type: 'object', additionalProperties: toJsonSchemaType(valueType) };
+  }
+
+  if (/^[IU](8|16|32|64|128)$/.test(helixType)) {
+    const schema: Record<string, any> = { type: 'integer' };
+    if (INTEGER_FORMATS[helixType]) {
+      schema.format = INTEGER_FORMATS[helixType];
+    }
+    if (helixType.startsWith('U')) {
+      schema.minimum = 0;
+    }
+    return schema;
+  }
+  if (helixType === 'F32') {
+    return { type: 'number', format: 'float' };
+  }
+  if (helixType === 'F64') {
+    return { type: 'number', format: 'double' };
+  }
+  if (normalizeParamType(helixType) === 'bool') {
+    return { type: 'boolean' };
+  }
+  if (helixType === 'Date') {
+    return { type: 'string', format: 'date-time' };
+  }
+  return { type: 'string' };
+}
+
+// TypeScript type string for a HelixDB type, used for client generation
+export function toTypeScriptType(helixType: string): string {
+  const elementType = arrayElementType(helixType);
+  if (elementType) {
+    const inner = toTypeScriptType(elementType);
+    return /^[\w.]+(\[\])*$/.test(inner) ? `${inner}[]` : `(${inner})[]`;
+  }
+  const valueType = mapValueType(helixType);
+  if (valueType) {
+    return `Record<string, ${toTypeScriptType(valueType)}>`;
+  }
+
+  if (/^[IUF](8|16|32|64|128)$/.test(helixType)) {
+    return 'number';
+  }
+  if (normalizeParamType(helixType) === 'bool') {
+    return 'boolean';
+  }
+  return 'string';
+}
+
+// OpenAPI 3.0 operation object for an endpoint
+export function toOpenApiOperation(endpoint: ApiEndpointInfo): Record<string, any> {
+  const operation: Record<string, any> = {
+    operationId: endpoint.query_name,
+    ...(endpoint.description ? { description: endpoint.description } : {}),
+    responses: {
+      '200': {
+        description: 'Query result',
+        content: { 'application/json': { schema: { type: 'object' } } }
+      },
+      '400': { $ref: '#/components/responses/Problem' },
+      '500': { $ref: '#/components/responses/Problem' }
+    }
+  };
+
+  if (endpoint.method === 'POST' || endpoint.method === 'PUT' || endpoint.method === 'PATCH') {
+    const properties: Record<string, any> = {};
+    for (const param of endpoint.parameters) {
+      properties[param.name] = toJsonSchemaType(param.param_type);
+    }
+    operation.requestBody = {
+      required: endpoint.parameters.length > 0,
+      content: {
+        'application/json': {
+          schema: {
+            type: 'object',
+            properties,
+            required: endpoint.parameters.map(param => param.name)
+          }
+        }
+      }
+    };
+  } else {
+    operation.parameters = endpoint.parameters.map(param => ({
+      name: param.name,
+      in: 'query',
+      required: true,
+      schema: toJsonSchemaType(param.param_type)
+    }));
+  }
+
+  return operation;
+}
+
+// Full OpenAPI 3.0 document covering every query endpoint
+export function buildOpenApiDocument(endpoints: ApiEndpointInfo[]): Record<string, any> {
+  const paths: Record<string, any> = {};
+  for (const endpoint of endpoints) {
+    paths[endpoint.path] = {
+      ...paths[endpoint.path],
+      [endpoint.method.toLowerCase()]: toOpenApiOperation(endpoint)
+    };
+  }
+
+  return {
+    openapi: '3.0.0',
+    info: {
+      title: 'Helix Dashboard API',
+      version: '1.0.0'
+    },
+    paths,
+    components: {
+      responses: {
+        Problem: {
+          description: 'RFC 7807 problem details',
+          content: {
+            'application/problem+json': {
+              schema: {
+                type: 'object',
+                properties: {
+                  type: { type: 'string' },
+                  title: { type: 'string' },
+                  status: { type: 'integer' },
+                  detail: { type: 'string' },
+                  instance: { type: 'string' }
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+  };
+}
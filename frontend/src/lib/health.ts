@@ -0,0 +1,58 @@
+import { dataSourceDescription, dataSourceDisplayName, isRemoteDataSource } from './helix';
+import { fetchIntrospect } from './introspect';
+
+// Rapid polling reuses the last result instead of hitting HelixDB every time
+const HEALTH_CACHE_MS = 5000;
+
+interface DataSourceInfo {
+  data_source: string;
+  data_source_description: string;
+  remote: boolean;
+}
+
+export type HealthStatus =
+  | ({ status: 'connected'; latency_ms: number; version?: string } & DataSourceInfo)
+  | ({ status: 'disconnected'; error: string } & DataSourceInfo);
+
+function dataSourceInfo(): DataSourceInfo {
+  return {
+    data_source: dataSourceDisplayName(),
+    data_source_description: dataSourceDescription(),
+    remote: isRemoteDataSource()
+  };
+}
+
+let lastHealthCheck: { checkedAt: number; health: HealthStatus } | null = null;
+
+async function checkHealth(): Promise<HealthStatus> {
+  const start = Date.now();
+  try {
+    const introspect = await fetchIntrospect();
+    const health: HealthStatus = {
+      status: 'connected',
+      ...dataSourceInfo(),
+      latency_ms: Date.now() - start
+    };
+    if (introspect.version) {
+      health.version = introspect.version;
+    }
+    return health;
+  } catch (error) {
+    return {
+      status: 'disconnected',
+      ...dataSourceInfo(),
+      error: error instanceof Error ? error.message : 'Unknown error'
+    };
+  }
+}
+
+export async function healthStatus(): Promise<HealthStatus> {
+  const now = Date.now();
+  if (lastHealthCheck && now - lastHealthCheck.checkedAt < HEALTH_CACHE_MS) {
+    return lastHealthCheck.health;
+  }
+
+  const health = await checkHealth();
+  lastHealthCheck = { checkedAt: now, health };
+  return health;
+}
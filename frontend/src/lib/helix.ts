@@ -0,0 +1,208 @@
+const DEFAULT_PORT = 6969;
+const DEFAULT_REQUEST_TIMEOUT_MS = 30000;
+
+const host = process.env.DOCKER_HOST_INTERNAL || process.env.HELIX_HOST || 'localhost';
+const port = process.env.HELIX_PORT || DEFAULT_PORT;
+const cloudUrl = process.env.HELIX_CLOUD_URL;
+
+export const helixUrl = cloudUrl ? cloudUrl : `http://${host}:${port}`;
+
+// The dashboard reads from either a local HelixDB instance or HelixDB Cloud, both via introspect
+export type DataSource = 'local-introspect' | 'cloud';
+
+export const dataSource: DataSource = cloudUrl ? 'cloud' : 'local-introspect';
+
+const DATA_SOURCE_NAMES: Record<DataSource, string> = {
+  'local-introspect': 'Local (Introspect)',
+  cloud: 'Cloud'
+};
+
+const DATA_SOURCE_DESCRIPTIONS: Record<DataSource, string> = {
+  'local-introspect': 'Schema and queries are read from a local HelixDB instance.',
+  cloud: 'Schema and queries are read from a HelixDB Cloud deployment.'
+};
+
+export function dataSourceDisplayName(source: DataSource = dataSource): string {
+  return DATA_SOURCE_NAMES[source];
+}
+
+export function dataSourceDescription(source: DataSource = dataSource): string {
+  return DATA_SOURCE_DESCRIPTIONS[source];
+}
+
+export function isRemoteDataSource(source: DataSource = dataSource): boolean {
+  return source === 'cloud';
+}
+
+const requestTimeoutMs = parseInt(process.env.HELIX_REQUEST_TIMEOUT_MS || '', 10) || DEFAULT_REQUEST_TIMEOUT_MS;
+
+// Join an endpoint onto the base URL, keeping any base path prefix (e.g. `/v1`) and collapsing
+// duplicate slashes. Endpoints are always relative so the API key is never sent to another host.
+export function resolveUrl(endpoint: string): string {
+  return `${helixUrl.replace(/\/+$/, '')}/${endpoint.replace(/^\/+/, '')}`;
+}
+
+// Append query parameters to an endpoint path, which may already contain a `?` or existing parameters
+export function withQueryParams(endpoint: string, params: URLSearchParams): string {
+  const query = params.toString();
+  if (!query) {
+    return endpoint;
+  }
+  if (!endpoint.includes('?')) {
+    return `${endpoint}?${query}`;
+  }
+  return endpoint.endsWith('?') || endpoint.endsWith('&') ? `${endpoint}${query}` : `${endpoint}&${query}`;
+}
+
+interface HelixErrorDetails {
+  status?: number;
+  retryAfterSecs?: number;
+  timedOut?: boolean;
+  // Raw response text when the body could not be parsed as JSON
+  rawBody?: string;
+}
+
+// Error raised for any failed request against the HelixDB instance
+export class HelixError extends Error {
+  readonly status?: number;
+  readonly retryAfterSecs?: number;
+  readonly timedOut: boolean;
+  readonly rawBody?: string;
+
+  constructor(message: string, readonly endpoint: string, details: HelixErrorDetails = {}) {
+    super(message);
+    this.name = 'HelixError';
+    this.status = details.status;
+    this.retryAfterSecs = details.retryAfterSecs;
+    this.timedOut = details.timedOut ?? false;
+    this.rawBody = details.rawBody;
+  }
+
+  get isDeserialize(): boolean {
+    return this.rawBody !== undefined;
+  }
+}
+
+interface HelixRequestOptions {
+  method?: string;
+  body?: unknown;
+  // Sent as `application/x-www-form-urlencoded` instead of a JSON body
+  form?: Record<string, string>;
+}
+
+function parseRetryAfter(header: string | null): number | undefined {
+  if (!header) return undefined;
+  const secs = parseInt(header, 10);
+  return isNaN(secs) ? undefined : secs;
+}
+
+function authHeaders(): Record<string, string> {
+  const headers: Record<string, string> = {};
+
+  if (process.env.HELIX_API_KEY) {
+    headers['x-api-key'] = process.env.HELIX_API_KEY;
+  }
+
+  return headers;
+}
+
+// Send a request to the HelixDB instance, mapping network failures and timeouts to HelixError
+async function helixFetch(endpoint: string, init: RequestInit): Promise<Response> {
+  try {
+    return await fetch(resolveUrl(endpoint), {
+      ...init,
+      signal: AbortSignal.timeout(requestTimeoutMs)
+    });
+  } catch (error) {
+    if (error instanceof Error && error.name === 'TimeoutError') {
+      throw new HelixError(`Request timed out after ${requestTimeoutMs}ms`, endpoint, { timedOut: true });
+    }
+    throw new HelixError(error instanceof Error ? error.message : 'Unknown error', endpoint);
+  }
+}
+
+function statusError(response: Response, endpoint: string): HelixError {
+  return new HelixError(`HTTP error: ${response.status}`, endpoint, {
+    status: response.status,
+    retryAfterSecs: parseRetryAfter(response.headers.get('retry-after')),
+    timedOut: response.status === 504
+  });
+}
+
+// Make an HTTP request to the HelixDB instance with optional API key
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export async function helixRequest(endpoint: string, options: HelixRequestOptions = {}): Promise<any> {
+  const headers = authHeaders();
+
+  let body: string | undefined;
+  if (options.form !== undefined) {
+    headers['Content-Type'] = 'application/x-www-form-urlencoded';
+    body = new URLSearchParams(options.form).toString();
+  } else if (options.body !== undefined) {
+    headers['Content-Type'] = 'application/json';
+    body = JSON.stringify(options.body);
+  }
+
+  const response = await helixFetch(endpoint, {
+    method: options.method || 'GET',
+    headers,
+    body
+  });
+
+  if (!response.ok) {
+    throw statusError(response, endpoint);
+  }
+
+  // Read the text first so the raw body can be reported if it is not valid JSON
+  const text = await response.text();
+  try {
+    return JSON.parse(text);
+  } catch (error) {
+    throw new HelixError(
+      `Failed to deserialize response from ${endpoint}: ${error instanceof Error ? error.message : 'Unknown error'}`,
+      endpoint,
+      { status: response.status, rawBody: text }
+    );
+  }
+}
+
+// Issue a HEAD request, returning whether the resource exists without transferring the body
+export async function helixHead(endpoint: string): Promise<boolean> {
+  const response = await helixFetch(endpoint, {
+    method: 'HEAD',
+    headers: authHeaders()
+  });
+
+  if (response.ok) {
+    return true;
+  }
+  if (response.status === 404) {
+    return false;
+  }
+
+  throw statusError(response, endpoint);
+}
+
+// Open a streaming (e.g. SSE) response from HelixDB, returning the raw body stream.
+// No request timeout is applied since the stream is long-lived; pass `signal` to close it.
+export async function helixSubscribe(endpoint: string, signal?: AbortSignal): Promise<ReadableStream<Uint8Array>> {
+  let response: Response;
+  try {
+    response = await fetch(resolveUrl(endpoint), {
+      method: 'GET',
+      headers: { ...authHeaders(), Accept: 'text/event-stream' },
+      signal
+    });
+  } catch (error) {
+    throw new HelixError(error instanceof Error ? error.message : 'Unknown error', endpoint);
+  }
+
+  if (!response.ok) {
+    throw statusError(response, endpoint);
+  }
+  if (!response.body) {
+    throw new HelixError('Response has no body to stream', endpoint, { status: response.status });
+  }
+
+  return response.body;
+}
@@ -0,0 +1,47 @@
+import { HelixError, helixRequest } from './helix';
+import { logger } from './logger';
+
+// Tried in order; older and newer HelixDB versions expose introspect under different paths
+const INTROSPECT_PATHS = ['introspect', 'introspect/v1', 'v1/introspect'];
+
+export interface QueryExample {
+  description: string;
+  params: Record<string, unknown>;
+}
+
+export interface IntrospectQuery {
+  name: string;
+  parameters?: Record<string, string>;
+  description?: string;
+  return_type?: string;
+  examples?: QueryExample[];
+}
+
+export interface IntrospectData {
+  schema?: any;
+  queries?: IntrospectQuery[];
+  version?: string;
+}
+
+// Fetch introspect data, falling back to versioned paths when the primary one returns 404
+export async function fetchIntrospect(): Promise<IntrospectData> {
+  let lastError: unknown;
+
+  for (const path of INTROSPECT_PATHS) {
+    try {
+      const data = await helixRequest(path);
+      if (path !== INTROSPECT_PATHS[0]) {
+        logger.info(`Introspect data served from fallback path /${path}`);
+      }
+      return data || {};
+    } catch (error) {
+      if (error instanceof HelixError && error.status === 404) {
+        lastError = error;
+        continue;
+      }
+      throw error;
+    }
+  }
+
+  throw lastError;
+}
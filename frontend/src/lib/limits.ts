@@ -0,0 +1,50 @@
+import type { NextRequest } from 'next/server';
+
+const DEFAULT_MAX_LIMIT = 300;
+
+export const MAX_LIMIT_HEADER = 'x-max-limit';
+
+export const maxLimit = parseInt(process.env.HELIX_MAX_LIMIT || '', 10) || DEFAULT_MAX_LIMIT;
+
+// Effective maximum for a request: `X-Max-Limit` can lower the configured maximum but never raise it
+export function resolveMaxLimit(request: NextRequest, max: number = maxLimit): number {
+  const headerLimit = parseInt(request.headers.get(MAX_LIMIT_HEADER) || '', 10);
+  return isNaN(headerLimit) || headerLimit <= 0 ? max : Math.min(headerLimit, max);
+}
+
+export function validateLimit(limit: string | undefined, max: number): number | undefined {
+  if (!limit) return undefined;
+  const numLimit = parseInt(limit, 10);
+  return isNaN(numLimit) ? undefined : Math.min(numLimit, max);
+}
+
+export const MAX_TRAVERSAL_DEPTH = 5;
+export const DEFAULT_MAX_NODES = 100;
+
+// Traversal depth must be within 1..=5 to prevent runaway queries; undefined when absent or out of range
+export function validateDepth(depth: string | undefined): number | undefined {
+  if (!depth) return undefined;
+  const numDepth = Number(depth);
+  return Number.isInteger(numDepth) && numDepth >= 1 && numDepth <= MAX_TRAVERSAL_DEPTH ? numDepth : undefined;
+}
+
+export const MAX_CONNECTION_NODES = 500;
+
+// Node cap for connection traversals, at most 500; undefined when absent or out of range
+export function validateMaxNodes(maxNodes: string | undefined): number | undefined {
+  if (!maxNodes) return undefined;
+  const numMaxNodes = Number(maxNodes);
+  return Number.isInteger(numMaxNodes) && numMaxNodes >= 1 && numMaxNodes <= MAX_CONNECTION_NODES ? numMaxNodes : undefined;
+}
+
+const DEFAULT_INTERVAL_MS = 5000;
+const MIN_INTERVAL_MS = 1000;
+const MAX_INTERVAL_MS = 60000;
+
+// Polling interval for SSE streams, defaulting to 5s and clamped to 1s..60s
+export function validateInterval(interval?: string | number): number {
+  if (interval === undefined || interval === '') return DEFAULT_INTERVAL_MS;
+  const numInterval = typeof interval === 'number' ? Math.floor(interval) : parseInt(interval, 10);
+  if (isNaN(numInterval) || numInterval <= 0) return DEFAULT_INTERVAL_MS;
+  return Math.min(Math.max(numInterval, MIN_INTERVAL_MS), MAX_INTERVAL_MS);
+}
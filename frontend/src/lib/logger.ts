@@ -0,0 +1,109 @@
+export type LogLevel = 'trace' | 'debug' | 'info' | 'warn' | 'error';
+
+const LEVELS: Record<LogLevel, number> = {
+  trace: 0,
+  debug: 1,
+  info: 2,
+  warn: 3,
+  error: 4
+};
+
+const DEFAULT_LOG_LEVEL: LogLevel = 'info';
+
+function parseLogLevel(level?: string): LogLevel {
+  const normalized = level?.toLowerCase();
+  return normalized && normalized in LEVELS ? normalized as LogLevel : DEFAULT_LOG_LEVEL;
+}
+
+const minLevel = LEVELS[parseLogLevel(process.env.HELIX_LOG_LEVEL)];
+
+export type LogFormat = 'compact' | 'pretty' | 'json';
+
+function parseLogFormat(format?: string): LogFormat {
+  const normalized = format?.toLowerCase();
+  return normalized === 'json' || normalized === 'pretty' ? normalized : 'compact';
+}
+
+const logFormat = parseLogFormat(process.env.HELIX_LOG_FORMAT);
+
+// Append log lines to HELIX_LOG_FILE as well as the console. Route handlers run on Node; the edge
+// runtime (middleware) has no file system, so it keeps logging to the console only.
+type LogFileWriter = (line: string, args: unknown[]) => void;
+
+function openLogFile(path?: string): LogFileWriter | undefined {
+  if (!path || process.env.NEXT_RUNTIME === 'edge') return undefined;
+
+  try {
+    // eslint-disable-next-line @typescript-eslint/no-require-imports
+    const { createWriteStream } = require('fs');
+    // eslint-disable-next-line @typescript-eslint/no-require-imports
+    const { format } = require('util');
+    const stream = createWriteStream(path, { flags: 'a' });
+    stream.on('error', (error: Error) => console.error(`Cannot write to HELIX_LOG_FILE '${path}':`, error.message));
+    // ANSI colour codes from the `pretty` format are stripped so the file stays plain text
+    return (line, args) => stream.write(format(line, ...args).replace(/\x1b\[[0-9;]*m/g, '') + '\n');
+  } catch (error) {
+    console.error(`Cannot open HELIX_LOG_FILE '${path}':`, error);
+    return undefined;
+  }
+}
+
+const writeLogFile = openLogFile(process.env.HELIX_LOG_FILE);
+
+// ANSI colours for the `pretty` format
+const COLORS: Record<LogLevel, string> = {
+  trace: '\x1b[90m',
+  debug: '\x1b[36m',
+  info: '\x1b[32m',
+  warn: '\x1b[33m',
+  error: '\x1b[31m'
+};
+const RESET = '\x1b[0m';
+
+function serializeArg(arg: unknown): unknown {
+  return arg instanceof Error ? { name: arg.name, message: arg.message, stack: arg.stack } : arg;
+}
+
+function formatLine(level: LogLevel, message: string, args: unknown[]): [string, unknown[]] {
+  const timestamp = new Date().toISOString();
+
+  switch (logFormat) {
+    case 'json': {
+      const entry: Record<string, unknown> = { timestamp, level, message };
+      if (args.length > 0) {
+        entry.args = args.map(serializeArg);
+      }
+      return [JSON.stringify(entry), []];
+    }
+    case 'pretty':
+      return [`${COLORS[level]}${level.toUpperCase().padEnd(5)}${RESET} \x1b[2m${timestamp}${RESET} ${message}`, args];
+    default:
+      return [`${timestamp} ${level.toUpperCase().padEnd(5)} ${message}`, args];
+  }
+}
+
+function log(level: LogLevel, message: string, ...args: unknown[]) {
+  if (LEVELS[level] < minLevel) return;
+
+  const [line, rest] = formatLine(level, message, args);
+  writeLogFile?.(line, rest);
+  switch (level) {
+    case 'error':
+      console.error(line, ...rest);
+      break;
+    case 'warn':
+      console.warn(line, ...rest);
+      break;
+    default:
+      console.log(line, ...rest);
+  }
+}
+
+// Leveled server-side logger, filtered by HELIX_LOG_LEVEL
+export const logger = {
+  trace: (message: string, ...args: unknown[]) => log('trace', message, ...args),
+  debug: (message: string, ...args: unknown[]) => log('debug', message, ...args),
+  info: (message: string, ...args: unknown[]) => log('info', message, ...args),
+  warn: (message: string, ...args: unknown[]) => log('warn', message, ...args),
+  error: (message: string, ...args: unknown[]) => log('error', message, ...args)
+};
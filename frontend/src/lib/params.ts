@@ -0,0 +1,97 @@
+import { ApiError } from './api-errors';
+import {
+  DEFAULT_MAX_NODES,
+  MAX_CONNECTION_NODES,
+  MAX_TRAVERSAL_DEPTH,
+  validateDepth,
+  validateMaxNodes
+} from './limits';
+
+export const MAX_SEARCH_CHARS = 100;
+
+// Letters (including accented Latin), digits, whitespace and the punctuation common in names and emails
+const VALID_SEARCH_CHARS = /^[A-Za-z0-9\u00C0-\u024F\s\-_.@']+$/;
+
+// Validate a search term, throwing an InvalidQuery error if it is too long or has unsupported characters
+export function validateSearchTerm(term: string): string {
+  const trimmed = term.trim();
+  if (!trimmed) {
+    throw ApiError.missingParameter('q');
+  }
+  if (trimmed.length > MAX_SEARCH_CHARS) {
+    throw ApiError.invalidQuery(`q must be at most ${MAX_SEARCH_CHARS} characters`);
+  }
+  if (!VALID_SEARCH_CHARS.test(trimmed)) {
+    throw ApiError.invalidQuery('q may only contain letters, digits, spaces and - _ . @ \'');
+  }
+  return trimmed;
+}
+
+export type ConnectionDirection = 'both' | 'incoming' | 'outgoing';
+
+const CONNECTION_DIRECTIONS: ConnectionDirection[] = ['both', 'incoming', 'outgoing'];
+
+export interface NodeConnectionsParams {
+  node_id: string;
+  depth?: number;
+  max_nodes?: number;
+  edge_type?: string;
+  include_direction?: ConnectionDirection;
+}
+
+// Parse and validate `/api/node-connections` parameters, throwing an InvalidQuery error on bad input
+export function parseNodeConnectionsParams(searchParams: URLSearchParams): NodeConnectionsParams {
+  const nodeId = searchParams.get('node_id');
+  if (!nodeId) {
+    throw ApiError.missingParameter('node_id');
+  }
+  const params: NodeConnectionsParams = { node_id: nodeId };
+
+  const depthParam = searchParams.get('depth');
+  if (depthParam) {
+    params.depth = validateDepth(depthParam);
+    if (params.depth === undefined) {
+      throw ApiError.invalidQuery(`depth must be an integer between 1 and ${MAX_TRAVERSAL_DEPTH}`);
+    }
+  }
+
+  const maxNodesParam = searchParams.get('max_nodes');
+  if (maxNodesParam) {
+    params.max_nodes = validateMaxNodes(maxNodesParam);
+    if (params.max_nodes === undefined) {
+      throw ApiError.invalidQuery(`max_nodes must be an integer between 1 and ${MAX_CONNECTION_NODES}`);
+    }
+  } else if (params.depth && params.depth > 1) {
+    // Multi-hop traversals are capped so the response stays a manageable size
+    params.max_nodes = DEFAULT_MAX_NODES;
+  }
+
+  const edgeType = searchParams.get('edge_type');
+  if (edgeType) {
+    if (!/^[a-zA-Z0-9_]+$/.test(edgeType)) {
+      throw ApiError.invalidQuery('edge_type may only contain letters, digits and underscores');
+    }
+    params.edge_type = edgeType;
+  }
+
+  const direction = searchParams.get('include_direction')?.toLowerCase();
+  if (direction) {
+    if (!CONNECTION_DIRECTIONS.includes(direction as ConnectionDirection)) {
+      throw ApiError.invalidQuery(`include_direction must be one of ${CONNECTION_DIRECTIONS.join(', ')}`);
+    }
+    params.include_direction = direction as ConnectionDirection;
+  }
+
+  return params;
+}
+
+// Query parameters forwarded to HelixDB, omitting unset fields
+export function nodeConnectionsSearchParams(params: NodeConnectionsParams): URLSearchParams {
+  const searchParams = new URLSearchParams();
+  for (const [key, value] of Object.entries(params)) {
+    if (value !== undefined) {
+      searchParams.append(key, value.toString());
+    }
+  }
+  return searchParams;
+}
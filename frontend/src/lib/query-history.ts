@@ -0,0 +1,28 @@
+const HISTORY_CAPACITY = 50;
+
+export interface QueryHistoryEntry {
+  timestamp: string;
+  result: unknown;
+}
+
+// Per-query results of recent executions, kept in memory only
+const histories = new Map<string, QueryHistoryEntry[]>();
+
+export function recordQueryResult(queryName: string, result: unknown) {
+  const history = histories.get(queryName) ?? [];
+  history.push({ timestamp: new Date().toISOString(), result });
+  if (history.length > HISTORY_CAPACITY) {
+    history.splice(0, history.length - HISTORY_CAPACITY);
+  }
+  histories.set(queryName, history);
+}
+
+// Most recent `limit` entries, oldest first
+export function getQueryHistory(queryName: string, limit = HISTORY_CAPACITY): QueryHistoryEntry[] {
+  const history = histories.get(queryName) ?? [];
+  return history.slice(-Math.min(Math.max(limit, 1), HISTORY_CAPACITY));
+}
+
+export function clearQueryHistory(queryName: string) {
+  histories.delete(queryName);
+}
@@ -0,0 +1,540 @@
+import { ApiError } from './api-errors';
+import { helixRequest } from './helix';
+import { fetchIntrospect } from './introspect';
+import { logger } from './logger';
+import { fetchSchema, hasProperty } from './schema';
+
+// Parse `0x`-prefixed hex integers such as `0x1A3F` or `-0x1`
+function parseHexInteger(value: string): number | undefined {
+    const match = /^\s*([+-]?)0x([0-9a-f]+)\s*$/i.exec(value);
+    if (!match) {
+        return undefined;
+    }
+    const magnitude = parseInt(match[2], 16);
+    return match[1] === '-' ? -magnitude : magnitude;
+}
+
+const NON_FINITE_MESSAGE = 'NaN and Infinity are not valid JSON numbers';
+
+function isNonFinite(raw: string, parsed: number): boolean {
+    return /^\s*[+-]?(nan|inf(inity)?)\s*$/i.test(raw) || parsed === Infinity || parsed === -Infinity;
+}
+
+// Split on a single delimiter, succeeding only if every element is a number
+function splitF64Array(value: string, delimiter: string | RegExp): number[] | undefined {
+    const parts = value.trim().split(delimiter);
+    const numbers: number[] = [];
+    for (const part of parts) {
+        const trimmed = part.trim();
+        const num = Number(trimmed);
+        if (isNonFinite(trimmed, num)) {
+            throw ApiError.invalidQuery(NON_FINITE_MESSAGE);
+        }
+        if (!trimmed || isNaN(num)) {
+            return undefined;
+        }
+        numbers.push(num);
+    }
+    return numbers;
+}
+
+// Parse an F64 array given as JSON or as comma, semicolon or whitespace separated values,
+// returning the original string if no format matches as a whole
+function parseF64Array(value: string): number[] | string {
+    const bracketed = value.startsWith('[') && value.endsWith(']');
+    if (bracketed) {
+        let parsed: unknown;
+        try {
+            parsed = JSON.parse(value);
+        } catch {
+            // Not a JSON array, try the delimited formats
+        }
+        if (Array.isArray(parsed) && parsed.every(entry => typeof entry === 'number')) {
+            // JSON.parse turns out-of-range literals such as 1e999 into Infinity
+            if (parsed.some(entry => !isFinite(entry))) {
+                throw ApiError.invalidQuery(NON_FINITE_MESSAGE);
+            }
+            return parsed;
+        }
+    }
+
+    // Bracketed input that is not valid JSON (e.g. containing NaN) is parsed element-wise
+    const elements = bracketed ? value.slice(1, -1) : value;
+    for (const delimiter of [',', ';', /\s+/]) {
+        const numbers = splitF64Array(elements, delimiter);
+        if (numbers) {
+            return numbers;
+        }
+    }
+
+    return value;
+}
+
+// Parse a `Map<String, F64>` given as a JSON object such as `{"a":1.0,"b":2.0}`
+function parseF64Map(value: string): any {
+    try {
+        const parsed = JSON.parse(value);
+        if (parsed && typeof parsed === 'object' && !Array.isArray(parsed)
+            && Object.values(parsed).every(entry => typeof entry === 'number')) {
+            return parsed;
+        }
+    } catch {
+        // Not valid JSON
+    }
+
+    return value;
+}
+
+const INTEGER_TYPES = ['I8', 'I16', 'I32', 'I64', 'U8', 'U16', 'U32', 'U64', 'U128'];
+const FLOAT_TYPES = ['F32', 'F64'];
+
+export function isIntegerType(paramType: string): boolean {
+    return INTEGER_TYPES.includes(paramType);
+}
+
+export function isFloatType(paramType: string): boolean {
+    return FLOAT_TYPES.includes(paramType);
+}
+
+export function isNumericType(paramType: string): boolean {
+    return isIntegerType(paramType) || isFloatType(paramType);
+}
+
+export function isStringType(paramType: string): boolean {
+    return paramType === 'String' || paramType === 'ID';
+}
+
+export function isArrayType(paramType: string): boolean {
+    return /^Array\(.+\)$/.test(paramType) || /^\[.+\]$/.test(paramType);
+}
+
+function parseInteger(value: string): number {
+    const hexVal = parseHexInteger(value);
+    if (hexVal !== undefined) {
+        return hexVal;
+    }
+    const intVal = parseInt(value, 10);
+    return isNaN(intVal) ? 0 : intVal;
+}
+
+function parseFloatValue(value: string): number {
+    // parseFloat accepts scientific notation such as `1.5e-4` or `3.14E+2`
+    const floatVal = parseFloat(value);
+    if (isNonFinite(value, floatVal)) {
+        throw ApiError.invalidQuery(NON_FINITE_MESSAGE);
+    }
+    return isNaN(floatVal) ? 0.0 : floatVal;
+}
+
+// Helper function to convert string values to appropriate types
+export function convertStringToType(value: string, paramType: string): any {
+    // An explicit null clears the parameter regardless of its declared type
+    if (value.trim().toLowerCase() === 'null') {
+        return null;
+    }
+
+    if (isStringType(paramType)) {
+        return value;
+    }
+    if (isIntegerType(paramType)) {
+        return parseInteger(value);
+    }
+    if (isFloatType(paramType)) {
+        return parseFloatValue(value);
+    }
+
+    switch (paramType) {
+        case 'Date':
+            return value;
+        case 'Boolean':
+        case 'Bool':
+            if (typeof value === 'boolean') return value;
+            const lowerValue = value.toLowerCase().trim();
+            return lowerValue === 'true' || lowerValue === '1' || lowerValue === 'yes';
+        case 'Array(F64)':
+        case '[F64]':
+            return parseF64Array(value);
+        case 'Map<String, F64>':
+        case 'Map<String,F64>':
+            return parseF64Map(value);
+        default:
+            return value;
+    }
+}
+
+// Look up a query definition by name in the introspect data
+export async function findIntrospectQuery(queryName: string): Promise<any | undefined> {
+    const introspectData = await fetchIntrospect();
+
+    if (introspectData && introspectData.queries) {
+        return introspectData.queries.find((q: any) => q.name === queryName);
+    }
+
+    return undefined;
+}
+
+// Find a query that creates edges of the given type, e.g. `createLikes` or `addLikesEdge`
+export async function findCreateEdgeQuery(edgeType: string): Promise<any | undefined> {
+    const introspectData = await fetchIntrospect();
+    const target = edgeType.toLowerCase();
+
+    return introspectData?.queries?.find((q: any) => {
+        const name = q.name.toLowerCase();
+        return ['create', 'add'].some(prefix =>
+            name === `${prefix}${target}` || name === `${prefix}${target}edge`
+        );
+    });
+}
+
+const DEFAULT_PARAM_TYPES_CACHE_MS = 30000;
+
+function parseCacheMs(value: string | undefined): number {
+    const ms = parseInt(value || '', 10);
+    return isNaN(ms) || ms < 0 ? DEFAULT_PARAM_TYPES_CACHE_MS : ms;
+}
+
+// How long per-query parameter types are reused before introspect is fetched again; `0` disables caching
+const paramTypesCacheMs = parseCacheMs(process.env.HELIX_PARAM_TYPES_CACHE_MS);
+
+const paramTypesCache = new Map<string, { paramTypes: Record<string, string>; cachedAt: number }>();
+
+export function clearParamTypesCache() {
+    paramTypesCache.clear();
+}
+
+function extractParamTypes(query: any): Record<string, string> {
+    const paramTypes: Record<string, string> = {};
+
+    if (query && query.parameters && typeof query.parameters === 'object') {
+        for (const [paramName, paramType] of Object.entries(query.parameters)) {
+            if (typeof paramType === 'string') {
+                paramTypes[paramName] = paramType;
+            }
+        }
+    }
+
+    return paramTypes;
+}
+
+// Helper function to get parameter types from introspect data
+export async function getQueryParamTypes(queryName: string): Promise<Record<string, string>> {
+    const cached = paramTypesCache.get(queryName);
+    if (cached && Date.now() - cached.cachedAt < paramTypesCacheMs) {
+        return cached.paramTypes;
+    }
+
+    try {
+        const introspectData = await fetchIntrospect();
+        const queries = introspectData?.queries ?? [];
+
+        // One introspect response refreshes the cache for every query it lists
+        if (paramTypesCacheMs > 0) {
+            const cachedAt = Date.now();
+            paramTypesCache.clear();
+            for (const query of queries) {
+                paramTypesCache.set(query.name, { paramTypes: extractParamTypes(query), cachedAt });
+            }
+        }
+
+        return extractParamTypes(queries.find((q: any) => q.name === queryName));
+    } catch (error) {
+        logger.warn('Could not fetch parameter types:', error);
+        return {};
+    }
+}
+
+// There is deliberately no `id-first` order: JavaScript objects always enumerate integer-like keys
+// before string keys, so `id` can never be placed ahead of numeric keys in a plain object result
+export type SortOrder = 'numeric-first-then-id' | 'alphabetical-only';
+
+const SORT_ORDERS: SortOrder[] = ['numeric-first-then-id', 'alphabetical-only'];
+
+function parseSortOrder(order?: string): SortOrder {
+    return SORT_ORDERS.find(o => o === order) || 'numeric-first-then-id';
+}
+
+// Key ordering applied to query results, configurable via HELIX_SORT_ORDER
+export const defaultSortOrder = parseSortOrder(process.env.HELIX_SORT_ORDER);
+
+function compareKeys(a: string, b: string): number {
+    return a < b ? -1 : a > b ? 1 : 0;
+}
+
+// Note that JavaScript objects always enumerate integer-like keys first, so
+// the numeric keys' position can only be honoured once serialised elsewhere
+function orderKeys(keys: string[], order: SortOrder): string[] {
+    if (order === 'alphabetical-only') {
+        return [...keys].sort(compareKeys);
+    }
+
+    const isNumeric = (key: string) => /^\d+$/.test(key);
+    const numericKeys = keys.filter(isNumeric).sort((a, b) => parseInt(a, 10) - parseInt(b, 10));
+    const idKeys = keys.filter(key => key === 'id');
+    const otherKeys = keys.filter(key => !isNumeric(key) && key !== 'id').sort(compareKeys);
+
+    return [...numericKeys, ...idKeys, ...otherKeys];
+}
+
+// Deeper values are returned unsorted so pathological nesting cannot overflow the stack
+export const MAX_SORT_DEPTH = 1000;
+
+function sortValue(value: any, order: SortOrder, depth: number, state: { truncated: boolean }): any {
+    if (depth > MAX_SORT_DEPTH) {
+        state.truncated = true;
+        return value;
+    }
+
+    if (value && typeof value === 'object' && !Array.isArray(value)) {
+        const sorted: any = {};
+        for (const key of orderKeys(Object.keys(value), order)) {
+            sorted[key] = sortValue(value[key], order, depth + 1, state);
+        }
+        return sorted;
+    } else if (Array.isArray(value)) {
+        return value.map(item => sortValue(item, order, depth + 1, state));
+    }
+
+    return value;
+}
+
+// Helper function to sort JSON objects with an explicit key ordering
+export function sortJsonObjectWithOrder(value: any, order: SortOrder): any {
+    const state = { truncated: false };
+    const sorted = sortValue(value, order, 0, state);
+    if (state.truncated) {
+        logger.warn(`JSON nesting exceeds ${MAX_SORT_DEPTH} levels, deeper values were left unsorted`);
+    }
+    return sorted;
+}
+
+// Helper function to sort JSON objects (replicating Rust backend behavior):
+// numeric keys, then `id`, then the remaining keys alphabetically
+export function sortJsonObject(value: any): any {
+    return sortJsonObjectWithOrder(value, defaultSortOrder);
+}
+
+function stripNullsValue(value: any, depth: number): any {
+    if (depth > MAX_SORT_DEPTH) {
+        return value;
+    }
+
+    if (value && typeof value === 'object' && !Array.isArray(value)) {
+        const stripped: any = {};
+        for (const [key, entry] of Object.entries(value)) {
+            if (entry !== null) {
+                stripped[key] = stripNullsValue(entry, depth + 1);
+            }
+        }
+        return stripped;
+    } else if (Array.isArray(value)) {
+        return value.map(item => stripNullsValue(item, depth + 1));
+    }
+
+    return value;
+}
+
+// Remove null-valued object entries recursively, including inside arrays; a top-level null is kept.
+// Values nested deeper than the sort depth limit are left as they are
+export function stripNulls(value: any): any {
+    return stripNullsValue(value, 0);
+}
+
+export function sortAndFilterNulls(value: any): any {
+    return stripNulls(sortJsonObject(value));
+}
+
+const VALID_QUERY_NAME = /^[A-Za-z0-9_]+$/;
+
+const MAX_PARAM_VALUE_LENGTH = 1024;
+
+export type ParamValidationError = 'InvalidKey' | 'ValueTooLong' | 'NullByte';
+
+// Reject parameter keys outside `[a-zA-Z0-9_]` and null-byte string values; with `checkLength`,
+// string values over 1024 characters are rejected too
+export function validateQueryParam(key: string, value: unknown, checkLength = false): ParamValidationError | null {
+    if (!/^[a-zA-Z0-9_]+$/.test(key)) {
+        return 'InvalidKey';
+    }
+    if (Array.isArray(value)) {
+        for (const item of value) {
+            const error = validateQueryParam(key, item, checkLength);
+            if (error) {
+                return error;
+            }
+        }
+    } else if (typeof value === 'string') {
+        if (checkLength && value.length > MAX_PARAM_VALUE_LENGTH) {
+            return 'ValueTooLong';
+        }
+        if (value.includes('\0')) {
+            return 'NullByte';
+        }
+    }
+    return null;
+}
+
+function paramValidationMessage(key: string, error: ParamValidationError): string {
+    switch (error) {
+        case 'InvalidKey':
+            return `Invalid parameter name '${key}': only letters, digits and underscores are allowed`;
+        case 'ValueTooLong':
+            return `Value for parameter '${key}' exceeds ${MAX_PARAM_VALUE_LENGTH} characters`;
+        case 'NullByte':
+            return `Value for parameter '${key}' contains a null byte`;
+    }
+}
+
+// Cap the length of ad-hoc query-string values; parameters with a declared type are checked by their conversion
+export async function validateQueryStringParams(queryName: string, params: Record<string, string | string[]>) {
+    const paramTypes = await getQueryParamTypes(queryName);
+
+    for (const [key, value] of Object.entries(params)) {
+        if (paramTypes[key]) {
+            continue;
+        }
+        const validationError = validateQueryParam(key, value, true);
+        if (validationError) {
+            throw ApiError.invalidQuery(paramValidationMessage(key, validationError));
+        }
+    }
+}
+
+export interface QueryResultMeta {
+    duration_ms: number;
+    result_count: number;
+    query_name: string;
+}
+
+export interface TimedResult<T> {
+    data: T;
+    meta: QueryResultMeta;
+}
+
+// Top-level array elements, or 1 for any other result
+function countResults(value: any): number {
+    return Array.isArray(value) ? value.length : 1;
+}
+
+export function withMetadata<T>(result: T, queryName: string, durationMs: number): TimedResult<T> {
+    return {
+        data: result,
+        meta: {
+            duration_ms: durationMs,
+            result_count: countResults(result),
+            query_name: queryName
+        }
+    };
+}
+
+// Gateways that only accept form-encoded bodies can be targeted with HELIX_AUTH_FORM=true
+const useFormEncoding = process.env.HELIX_AUTH_FORM === 'true';
+
+// Flatten parameters to form fields; non-string values are sent as their JSON text
+function toFormFields(params: Record<string, any>): Record<string, string> {
+    const fields: Record<string, string> = {};
+    for (const [key, value] of Object.entries(params)) {
+        fields[key] = typeof value === 'string' ? value : JSON.stringify(value);
+    }
+    return fields;
+}
+
+// Query-string options consumed by the API routes themselves rather than forwarded to HelixDB
+const CONTROL_PARAMS = ['strip_nulls', 'include_metadata', 'format', 'interval_ms'];
+
+// Remove route options from the query-string params and return them. A name the query declares as one
+// of its own parameters is left in place and forwarded, so e.g. `exportData(format: String)` stays callable.
+export async function takeControlParams(queryName: string, params: Record<string, string | string[]>): Promise<Record<string, string>> {
+    const present = CONTROL_PARAMS.filter(name => name in params);
+    if (present.length === 0) {
+        return {};
+    }
+
+    const paramTypes = await getQueryParamTypes(queryName);
+    const controls: Record<string, string> = {};
+    for (const name of present) {
+        if (paramTypes[name]) {
+            continue;
+        }
+        const value = params[name];
+        controls[name] = Array.isArray(value) ? value[0] : value;
+        delete params[name];
+    }
+    return controls;
+}
+
+// Repeated query-string parameters become an array; single values stay plain strings
+export function searchParamsToObject(searchParams: URLSearchParams): Record<string, string | string[]> {
+    const params: Record<string, string | string[]> = {};
+    for (const key of new Set(searchParams.keys())) {
+        const values = searchParams.getAll(key);
+        params[key] = values.length === 1 ? values[0] : values;
+    }
+    return params;
+}
+
+// Element type of `Array(T)` or `[T]`, or the type itself for scalars
+function arrayItemType(paramType: string): string {
+    const match = /^Array\((.+)\)$/.exec(paramType) || /^\[(.+)\]$/.exec(paramType);
+    return match ? match[1].trim() : paramType;
+}
+
+// Object parameters whose introspected type names a node, edge or vector type may only use that type's properties
+async function validateEntityParams(params: Record<string, any>, paramTypes: Record<string, string>) {
+    const entityParams = Object.entries(params).filter(([key, value]) =>
+        paramTypes[key] && value && typeof value === 'object' && !Array.isArray(value));
+    if (entityParams.length === 0) {
+        return;
+    }
+
+    const schema = await fetchSchema();
+    const entityNames = new Set([...schema.nodes, ...schema.edges, ...schema.vectors].map(entity => entity.name));
+    for (const [key, value] of entityParams) {
+        const typeName = paramTypes[key];
+        if (!entityNames.has(typeName)) {
+            continue;
+        }
+        for (const property of Object.keys(value)) {
+            if (!hasProperty(schema, typeName, property)) {
+                throw ApiError.invalidQuery(`Parameter '${key}' has unknown property '${property}' for type '${typeName}'`);
+            }
+        }
+    }
+}
+
+// Convert parameters to their introspected types, execute the query and sort the result
+export async function executeQuery(queryName: string, params: Record<string, any>): Promise<any> {
+    // Query names become the HelixDB request path, so only plain identifiers are allowed
+    if (!VALID_QUERY_NAME.test(queryName)) {
+        throw ApiError.invalidQuery(`Invalid query name '${queryName}': only letters, digits and underscores are allowed`);
+    }
+
+    // Get parameter types and convert values appropriately
+    const paramTypes = await getQueryParamTypes(queryName);
+    await validateEntityParams(params, paramTypes);
+    const convertedParams: any = {};
+
+    for (const [key, value] of Object.entries(params)) {
+        const validationError = validateQueryParam(key, value);
+        if (validationError) {
+            throw ApiError.invalidQuery(paramValidationMessage(key, validationError));
+        }
+
+        if (typeof value === 'string' && paramTypes[key]) {
+            convertedParams[key] = convertStringToType(value, paramTypes[key]);
+        } else if (Array.isArray(value) && paramTypes[key] && value.every(item => typeof item === 'string')) {
+            // Repeated query-string parameters are converted element by element
+            const itemType = arrayItemType(paramTypes[key]);
+            convertedParams[key] = value.map(item => convertStringToType(item, itemType));
+        } else {
+            convertedParams[key] = value;
+        }
+    }
+
+    // Execute the query
+    const result = useFormEncoding
+        ? await helixRequest(queryName, { method: 'POST', form: toFormFields(convertedParams) })
+        : await helixRequest(queryName, { method: 'POST', body: convertedParams });
+
+    // Sort the result to match Rust backend behavior
+    return sortJsonObject(result);
+}
@@ -0,0 +1,89 @@
+import type { NextRequest } from 'next/server';
+import { ApiError } from './api-errors';
+import { logger } from './logger';
+
+const DEFAULT_RATE_LIMIT_RPS = 100;
+const WINDOW_MS = 1000;
+// Stale windows are pruned once this many clients are tracked
+const MAX_TRACKED_CLIENTS = 10000;
+
+function parseRateLimit(value: string | undefined): number {
+  const rps = parseInt(value || '', 10);
+  return isNaN(rps) || rps < 0 ? DEFAULT_RATE_LIMIT_RPS : rps;
+}
+
+// Requests per second allowed per client IP; `0` disables rate limiting
+export const rateLimitRps = parseRateLimit(process.env.HELIX_RATE_LIMIT_RPS);
+
+interface RateWindow {
+  start: number;
+  count: number;
+}
+
+const rateWindows = new Map<string, RateWindow>();
+
+let warnedNoClientIp = false;
+
+// Proxy headers are client-controlled unless a trusted reverse proxy sets them
+const trustProxy = process.env.HELIX_TRUST_PROXY === 'true';
+
+// Client IP as reported by the trusted proxy: `X-Real-IP`, or the last `X-Forwarded-For` entry (the one the
+// proxy appended). Returns undefined when the proxy is not trusted or no address is present.
+export function clientIp(request: NextRequest): string | undefined {
+  if (!trustProxy) {
+    return undefined;
+  }
+
+  const realIp = request.headers.get('x-real-ip')?.trim();
+  if (realIp) {
+    return realIp;
+  }
+  const forwardedFor = request.headers.get('x-forwarded-for');
+  const last = forwardedFor?.split(',').pop()?.trim();
+  return last || undefined;
+}
+
+function pruneWindows(now: number) {
+  for (const [ip, entry] of rateWindows) {
+    if (now - entry.start >= WINDOW_MS) {
+      rateWindows.delete(ip);
+    }
+  }
+}
+
+// Fixed one-second window per client IP, returning a 429 error once the limit is exceeded
+export function checkRateLimit(request: NextRequest): ApiError | null {
+  if (rateLimitRps === 0) {
+    return null;
+  }
+
+  // Without a client address every caller would share one bucket, so no limit is enforced
+  const ip = clientIp(request);
+  if (!ip) {
+    if (!warnedNoClientIp) {
+      warnedNoClientIp = true;
+      logger.warn(
+        `HELIX_RATE_LIMIT_RPS is ${rateLimitRps} but no client IP is available, so rate limiting is not enforced. ` +
+        'Set HELIX_TRUST_PROXY=true behind a reverse proxy that sets X-Real-IP or X-Forwarded-For.'
+      );
+    }
+    return null;
+  }
+
+  const now = Date.now();
+  const entry = rateWindows.get(ip);
+
+  if (!entry || now - entry.start >= WINDOW_MS) {
+    if (rateWindows.size >= MAX_TRACKED_CLIENTS) {
+      pruneWindows(now);
+    }
+    rateWindows.set(ip, { start: now, count: 1 });
+    return null;
+  }
+
+  entry.count += 1;
+  if (entry.count > rateLimitRps) {
+    return ApiError.rateLimited(1, `Rate limit of ${rateLimitRps} requests per second exceeded`);
+  }
+  return null;
+}
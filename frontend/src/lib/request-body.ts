@@ -0,0 +1,57 @@
+import type { NextRequest } from 'next/server';
+import { ApiError } from './api-errors';
+
+const DEFAULT_MAX_BODY_BYTES = 1024 * 1024;
+
+export const maxBodyBytes = parseInt(process.env.MAX_BODY_BYTES || '', 10) || DEFAULT_MAX_BODY_BYTES;
+
+// Reject requests whose declared Content-Length exceeds the limit before reading the body
+export function checkContentLength(request: NextRequest): ApiError | null {
+  const contentLength = parseInt(request.headers.get('content-length') || '', 10);
+  if (!isNaN(contentLength) && contentLength > maxBodyBytes) {
+    return ApiError.tooLarge(contentLength, maxBodyBytes);
+  }
+  return null;
+}
+
+// Read the raw request body, enforcing the size limit even without a Content-Length header
+export async function readBodyText(request: NextRequest): Promise<string> {
+  const tooLarge = checkContentLength(request);
+  if (tooLarge) {
+    throw tooLarge;
+  }
+
+  if (!request.body) {
+    return '';
+  }
+
+  // Count bytes as they arrive so a chunked body is cut off at the limit instead of buffered whole
+  const reader = request.body.getReader();
+  const decoder = new TextDecoder();
+  let size = 0;
+  let text = '';
+  for (;;) {
+    const { done, value } = await reader.read();
+    if (done) break;
+    size += value.byteLength;
+    if (size > maxBodyBytes) {
+      await reader.cancel();
+      throw ApiError.tooLarge(size, maxBodyBytes);
+    }
+    text += decoder.decode(value, { stream: true });
+  }
+
+  return text + decoder.decode();
+}
+
+// Write requests with a non-empty body must declare it as JSON
+export function checkJsonContentType(request: NextRequest, bodyText: string): ApiError | null {
+  if (!bodyText.trim()) {
+    return null;
+  }
+  const contentType = request.headers.get('content-type')?.split(';')[0].trim().toLowerCase();
+  if (contentType !== 'application/json' && !contentType?.endsWith('+json')) {
+    return ApiError.unsupportedMediaType('Content-Type must be application/json for write requests');
+  }
+  return null;
+}
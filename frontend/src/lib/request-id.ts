@@ -0,0 +1,8 @@
+import type { NextRequest } from 'next/server';
+
+export const REQUEST_ID_HEADER = 'x-request-id';
+
+// Correlation ID assigned to the request by the middleware
+export function getRequestId(request: NextRequest): string | undefined {
+  return request.headers.get(REQUEST_ID_HEADER) || undefined;
+}
@@ -0,0 +1,62 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { logger } from './logger';
+import { getRequestId } from './request-id';
+
+export const ENVELOPE_HEADER = 'x-envelope';
+export const REQUEST_START_HEADER = 'x-request-start';
+
+const MAX_LOG_BODY_BYTES = 65536;
+
+const logResponses = process.env.HELIX_LOG_RESPONSES === 'true';
+
+export interface EnvelopeMeta {
+  request_id: string | null;
+  timestamp: string;
+  duration_ms: number | null;
+}
+
+export interface EnvelopeError {
+  code: string;
+  message: string;
+}
+
+// Clients opt into the envelope with `X-Envelope: true`
+export function wantsEnvelope(request: NextRequest): boolean {
+  return request.headers.get(ENVELOPE_HEADER)?.toLowerCase() === 'true';
+}
+
+function buildMeta(request: NextRequest): EnvelopeMeta {
+  const start = parseInt(request.headers.get(REQUEST_START_HEADER) || '', 10);
+
+  return {
+    request_id: getRequestId(request) || null,
+    timestamp: new Date().toISOString(),
+    duration_ms: isNaN(start) ? null : Date.now() - start
+  };
+}
+
+export function envelope(request: NextRequest, data: unknown, error: EnvelopeError | null = null) {
+  return {
+    data: error ? null : data,
+    meta: buildMeta(request),
+    error
+  };
+}
+
+// JSON response that is wrapped in the envelope when the client asked for it
+export function jsonResponse(request: NextRequest, body: unknown, init?: ResponseInit): NextResponse {
+  const payload = wantsEnvelope(request) ? envelope(request, body) : body;
+  if (logResponses) {
+    logResponseBody(request, payload);
+  }
+  return NextResponse.json(payload, init);
+}
+
+// Log the serialized body at debug level, truncated so large responses cannot blow up the logs
+function logResponseBody(request: NextRequest, payload: unknown) {
+  const bytes = new TextEncoder().encode(JSON.stringify(payload) ?? '');
+  const text = bytes.length > MAX_LOG_BODY_BYTES
+    ? new TextDecoder().decode(bytes.slice(0, MAX_LOG_BODY_BYTES)) + '[truncated]'
+    : new TextDecoder().decode(bytes);
+  logger.debug(`Response body for ${request.nextUrl.pathname}: ${text}`);
+}
@@ -0,0 +1,286 @@
+import { fetchIntrospect } from './introspect';
+import type { EdgeType, NodeType, SchemaInfo } from '@/utils/schema';
+
+// Helper function to create empty schema
+export function createEmptySchema(): SchemaInfo {
+  return {
+    nodes: [],
+    edges: [],
+    vectors: []
+  };
+}
+
+// Fetch the schema from the introspect endpoint
+export async function fetchSchema(): Promise<SchemaInfo> {
+  const introspectData = await fetchIntrospect();
+
+  if (!introspectData || !introspectData.schema) {
+    return createEmptySchema();
+  }
+
+  const schema = introspectData.schema;
+
+  // Transform schema to match Rust backend format
+  return {
+    nodes: schema.nodes?.map((node: any) => ({
+      name: node.name,
+      node_type: node.node_type || "N", // Add missing node_type
+      properties: node.properties
+    })) || [],
+    edges: schema.edges?.map((edge: any) => ({
+      name: edge.name,
+      from_node: edge.from_node || edge.from, // Handle both formats
+      to_node: edge.to_node || edge.to,       // Handle both formats
+      properties: edge.properties
+    })) || [],
+    vectors: schema.vectors?.map((vector: any) => ({
+      name: vector.name,
+      vector_type: vector.vector_type || "V", // Add missing vector_type
+      properties: vector.properties
+    })) || []
+  };
+}
+
+export function getNode(schema: SchemaInfo, name: string): NodeType | undefined {
+  return schema.nodes.find(node => node.name === name);
+}
+
+export function getEdge(schema: SchemaInfo, name: string): EdgeType | undefined {
+  return schema.edges.find(edge => edge.name === name);
+}
+
+export interface SchemaStatistics {
+  node_count: number;
+  edge_count: number;
+  vector_count: number;
+  total_properties: number;
+  property_type_distribution: Record<string, number>;
+  most_connected_node: string | null;
+}
+
+// Counts of schema entities and property types; the most connected node has the most incoming plus outgoing edge types
+export function schemaStatistics(schema: SchemaInfo): SchemaStatistics {
+  const distribution: Record<string, number> = {};
+  let totalProperties = 0;
+  for (const entity of [...schema.nodes, ...schema.edges, ...schema.vectors]) {
+    for (const type of Object.values(entity.properties || {})) {
+      distribution[type] = (distribution[type] ?? 0) + 1;
+      totalProperties++;
+    }
+  }
+
+  const connections = new Map<string, number>(schema.nodes.map(node => [node.name, 0]));
+  for (const edge of schema.edges) {
+    for (const name of [edge.from_node, edge.to_node]) {
+      if (connections.has(name)) {
+        connections.set(name, connections.get(name)! + 1);
+      }
+    }
+  }
+
+  let mostConnected: string | null = null;
+  let maxConnections = 0;
+  for (const [name, count] of connections) {
+    if (count > maxConnections) {
+      mostConnected = name;
+      maxConnections = count;
+    }
+  }
+
+  return {
+    node_count: schema.nodes.length,
+    edge_count: schema.edges.length,
+    vector_count: schema.vectors.length,
+    total_properties: totalProperties,
+    property_type_distribution: distribution,
+    most_connected_node: mostConnected
+  };
+}
+
+// Type of a property on a node, edge or vector type, searched in that order
+export function propertyType(schema: SchemaInfo, typeName: string, property: string): string | undefined {
+  const entity = [...schema.nodes, ...schema.edges, ...schema.vectors].find(item => item.name === typeName);
+  return entity?.properties?.[property];
+}
+
+export function hasProperty(schema: SchemaInfo, typeName: string, property: string): boolean {
+  return propertyType(schema, typeName, property) !== undefined;
+}
+
+export function isSelfReferential(edge: EdgeType): boolean {
+  return edge.from_node === edge.to_node;
+}
+
+// Node types forming cycles in the edge type graph, as strongly connected components (Tarjan's algorithm).
+// Single-node self-references are expected in HelixDB and are not reported.
+export function findCycles(schema: SchemaInfo): string[][] {
+  const adjacency = new Map<string, string[]>();
+  for (const edge of schema.edges) {
+    if (isSelfReferential(edge)) continue;
+    adjacency.set(edge.from_node, [...(adjacency.get(edge.from_node) ?? []), edge.to_node]);
+    if (!adjacency.has(edge.to_node)) {
+      adjacency.set(edge.to_node, []);
+    }
+  }
+
+  const indices = new Map<string, number>();
+  const lowLinks = new Map<string, number>();
+  const stack: string[] = [];
+  const onStack = new Set<string>();
+  const cycles: string[][] = [];
+  let nextIndex = 0;
+
+  const visit = (node: string) => {
+    indices.set(node, nextIndex);
+    lowLinks.set(node, nextIndex);
+    nextIndex++;
+    stack.push(node);
+    onStack.add(node);
+
+    for (const target of adjacency.get(node) ?? []) {
+      if (!indices.has(target)) {
+        visit(target);
+        lowLinks.set(node, Math.min(lowLinks.get(node)!, lowLinks.get(target)!));
+      } else if (onStack.has(target)) {
+        lowLinks.set(node, Math.min(lowLinks.get(node)!, indices.get(target)!));
+      }
+    }
+
+    if (lowLinks.get(node) === indices.get(node)) {
+      const component: string[] = [];
+      let member: string;
+      do {
+        member = stack.pop()!;
+        onStack.delete(member);
+        component.push(member);
+      } while (member !== node);
+
+      if (component.length > 1) {
+        cycles.push(component.reverse());
+      }
+    }
+  };
+
+  for (const node of adjacency.keys()) {
+    if (!indices.has(node)) {
+      visit(node);
+    }
+  }
+
+  return cycles;
+}
+
+// Which kind of schema type a name refers to, if any
+export function entityKind(schema: SchemaInfo, name: string): 'node' | 'edge' | 'vector' | undefined {
+  if (schema.nodes.some(node => node.name === name)) return 'node';
+  if (schema.edges.some(edge => edge.name === name)) return 'edge';
+  if (schema.vectors.some(vector => vector.name === name)) return 'vector';
+  return undefined;
+}
+
+// All schema-level paths from one node type to another within `maxDepth` edge hops, found by BFS.
+// Each path alternates `[Node, Edge, Node, ...]` and never revisits a node, so cycles terminate.
+export function findPath(schema: SchemaInfo, fromNode: string, toNode: string, maxDepth: number): string[][] {
+  const paths: string[][] = [];
+  let frontier: string[][] = [[fromNode]];
+
+  for (let depth = 0; depth < maxDepth && frontier.length > 0; depth++) {
+    const next: string[][] = [];
+
+    for (const path of frontier) {
+      const current = path[path.length - 1];
+      const visited = path.filter((_, index) => index % 2 === 0);
+
+      for (const edge of schema.edges) {
+        if (edge.from_node !== current) {
+          continue;
+        }
+
+        const extended = [...path, edge.name, edge.to_node];
+        if (edge.to_node === toNode) {
+          paths.push(extended);
+        } else if (!visited.includes(edge.to_node)) {
+          next.push(extended);
+        }
+      }
+    }
+
+    frontier = next;
+  }
+
+  return paths;
+}
+
+function toMermaidType(helixType: string): string {
+  if (/^[IUF](8|16|32|64|128)$/.test(helixType)) {
+    return 'number';
+  }
+  if (helixType === 'Boolean' || helixType === 'Bool') {
+    return 'boolean';
+  }
+  return 'string';
+}
+
+// Mermaid `erDiagram` with node types as entities and edge types as relationships
+export function toMermaidDiagram(schema: SchemaInfo): string {
+  const lines = ['erDiagram'];
+
+  for (const node of schema.nodes) {
+    const properties = Object.entries(node.properties || {});
+    if (properties.length === 0) {
+      lines.push(`    ${node.name}`);
+      continue;
+    }
+
+    lines.push(`    ${node.name} {`);
+    for (const [name, type] of properties) {
+      lines.push(`        ${toMermaidType(type)} ${name}`);
+    }
+    lines.push('    }');
+  }
+
+  for (const edge of schema.edges) {
+    lines.push(`    ${edge.from_node} ||--o{ ${edge.to_node} : "${edge.name}"`);
+  }
+
+  return lines.join('\n') + '\n';
+}
+
+function escapeHtml(text: string): string {
+  return text.replace(/&/g, '&amp;').replace(/</g, '&lt;').replace(/>/g, '&gt;').replace(/"/g, '&quot;');
+}
+
+function escapeDotString(text: string): string {
+  return text.replace(/\\/g, '\\\\').replace(/"/g, '\\"');
+}
+
+// HTML-like table label with the entity name and type in the header row and one row per property
+function dotTableLabel(name: string, entityType: string, properties: Record<string, string>): string {
+  const rows = [`<TR><TD><B>${escapeHtml(name)}</B> (${escapeHtml(entityType)})</TD></TR>`];
+  for (const [property, type] of Object.entries(properties || {})) {
+    rows.push(`<TR><TD ALIGN="LEFT">${escapeHtml(property)}: ${escapeHtml(type)}</TD></TR>`);
+  }
+  return `<<TABLE BORDER="0" CELLBORDER="1" CELLSPACING="0">${rows.join('')}</TABLE>>`;
+}
+
+// Graphviz DOT graph with node and vector types as nodes and edge types as labelled edges
+export function toGraphvizDot(schema: SchemaInfo, directed = true): string {
+  const connector = directed ? '->' : '--';
+  const lines = [`${directed ? 'digraph' : 'graph'} {`, '    node [shape=plaintext];'];
+
+  for (const node of schema.nodes) {
+    lines.push(`    "${escapeDotString(node.name)}" [label=${dotTableLabel(node.name, node.node_type, node.properties)}];`);
+  }
+  for (const vector of schema.vectors) {
+    lines.push(`    "${escapeDotString(vector.name)}" [label=${dotTableLabel(vector.name, vector.vector_type, vector.properties)}];`);
+  }
+
+  for (const edge of schema.edges) {
+    lines.push(
+      `    "${escapeDotString(edge.from_node)}" ${connector} "${escapeDotString(edge.to_node)}" [label="${escapeDotString(edge.name)}"];`
+    );
+  }
+
+  lines.push('}');
+  return lines.join('\n') + '\n';
+}
@@ -0,0 +1,34 @@
+import { dataSource } from './helix';
+
+const startedAt = Date.now();
+
+let requestsTotal = 0;
+let errorsTotal = 0;
+
+// Wrap a route handler so every request it serves, and every 4xx/5xx or thrown error, is counted
+export function withStats<Args extends unknown[]>(
+  handler: (...args: Args) => Promise<Response>
+): (...args: Args) => Promise<Response> {
+  return async (...args: Args) => {
+    requestsTotal++;
+    try {
+      const response = await handler(...args);
+      if (response.status >= 400) {
+        errorsTotal++;
+      }
+      return response;
+    } catch (error) {
+      errorsTotal++;
+      throw error;
+    }
+  };
+}
+
+export function getStats() {
+  return {
+    requests_total: requestsTotal,
+    errors_total: errorsTotal,
+    uptime_secs: Math.floor((Date.now() - startedAt) / 1000),
+    data_source: dataSource
+  };
+}
@@ -0,0 +1,25 @@
+// Running query streams, so their polling interval can be changed while they are open
+interface RegisteredStream {
+  queryName: string;
+  setIntervalMs: (intervalMs: number) => void;
+}
+
+const streams = new Map<string, RegisteredStream>();
+
+export function registerStream(streamId: string, stream: RegisteredStream) {
+  streams.set(streamId, stream);
+}
+
+export function unregisterStream(streamId: string) {
+  streams.delete(streamId);
+}
+
+// Returns false when no open stream of this query has the given ID
+export function updateStreamInterval(streamId: string, queryName: string, intervalMs: number): boolean {
+  const stream = streams.get(streamId);
+  if (!stream || stream.queryName !== queryName) {
+    return false;
+  }
+  stream.setIntervalMs(intervalMs);
+  return true;
+}
@@ -0,0 +1,56 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { buildCorsHeaders, parseCorsOrigins } from '@/lib/cors';
+import { checkRateLimit } from '@/lib/rate-limit';
+import { checkContentLength } from '@/lib/request-body';
+import { REQUEST_ID_HEADER } from '@/lib/request-id';
+import { REQUEST_START_HEADER } from '@/lib/response';
+import { logger } from '@/lib/logger';
+
+const corsOrigins = parseCorsOrigins(process.env.HELIX_CORS_ORIGINS);
+
+export function middleware(request: NextRequest) {
+  const corsHeaders = buildCorsHeaders(request.headers.get('origin'), corsOrigins);
+
+  // Answer CORS preflight requests directly
+  if (request.method === 'OPTIONS' && corsHeaders) {
+    return new NextResponse(null, { status: 204, headers: corsHeaders });
+  }
+
+  // Reuse the caller's correlation ID if present, otherwise generate one
+  const requestId = request.headers.get(REQUEST_ID_HEADER) || crypto.randomUUID();
+  logger.trace(`[${requestId}] ${request.method} ${request.nextUrl.pathname}`);
+
+  const requestHeaders = new Headers(request.headers);
+  requestHeaders.set(REQUEST_ID_HEADER, requestId);
+  requestHeaders.set(REQUEST_START_HEADER, Date.now().toString());
+  const taggedRequest = new NextRequest(request, { headers: requestHeaders });
+
+  // Early error responses carry the same request ID and CORS headers as routed ones
+  const withResponseHeaders = (response: NextResponse): NextResponse => {
+    response.headers.set(REQUEST_ID_HEADER, requestId);
+    if (corsHeaders) {
+      for (const [name, value] of Object.entries(corsHeaders)) {
+        response.headers.set(name, value);
+      }
+    }
+    return response;
+  };
+
+  const rateLimited = checkRateLimit(request);
+  if (rateLimited) {
+    return withResponseHeaders(rateLimited.toResponse(taggedRequest));
+  }
+
+  const tooLarge = checkContentLength(request);
+  if (tooLarge) {
+    return withResponseHeaders(tooLarge.toResponse(taggedRequest));
+  }
+
+  return withResponseHeaders(NextResponse.next({
+    request: { headers: requestHeaders }
+  }));
+}
+
+export const config = {
+  matcher: '/api/:path*'
+};
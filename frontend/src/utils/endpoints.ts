@@ -6,6 +6,9 @@ interface ApiEndpointInfo {
         name: string;
         param_type: string;
     }>;
+    description?: string;
+    return_type?: string;
+    curl_example?: string;
 }
 
 interface EndpointConfig {
@@ -31,7 +34,9 @@ function convertToFrontendFormat(apiEndpoint: ApiEndpointInfo): EndpointConfig {
         .trim();
 
     let description = '';
-    if (apiEndpoint.query_name.startsWith('create')) {
+    if (apiEndpoint.description) {
+        description = apiEndpoint.description;
+    } else if (apiEndpoint.query_name.startsWith('create')) {
         description = `Create a new ${extractEntityName(apiEndpoint.query_name)}`;
     } else if (apiEndpoint.query_name.startsWith('get')) {
         description = `Retrieve ${extractEntityName(apiEndpoint.query_name)} data`;
@@ -51,16 +56,7 @@ function convertToFrontendFormat(apiEndpoint: ApiEndpointInfo): EndpointConfig {
         description: generateParamDescription(param.name)
     }));
 
-    // eslint-disable-next-line @typescript-eslint/no-explicit-any
-    let body: any = undefined;
-    if (apiEndpoint.method === 'POST' || apiEndpoint.method === 'PUT') {
-        body = {};
-        apiEndpoint.parameters
-            .filter(p => !p.name.endsWith('_id') && p.name !== 'id')
-            .forEach(param => {
-                body[param.name] = getDefaultValueForType(param.param_type);
-            });
-    }
+    const body = generateDefaultBody(apiEndpoint.method, apiEndpoint.parameters);
 
     const url = `/api/query/${apiEndpoint.query_name}`;
 
@@ -74,6 +70,21 @@ function convertToFrontendFormat(apiEndpoint: ApiEndpointInfo): EndpointConfig {
     };
 }
 
+// POST, PUT and PATCH requests carry every parameter, IDs included, in the body
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+function generateDefaultBody(method: string, parameters: ApiEndpointInfo['parameters']): any {
+    if (method !== 'POST' && method !== 'PUT' && method !== 'PATCH') {
+        return undefined;
+    }
+
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    const body: Record<string, any> = {};
+    parameters.forEach(param => {
+        body[param.name] = getDefaultValueForType(param.param_type);
+    });
+    return body;
+}
+
 function extractEntityName(queryName: string): string {
     const withoutPrefix = queryName
         .replace(/^(create|get|update|delete|add|remove|assign|link)/, '')
@@ -197,11 +208,13 @@ export function convertParamValue(value: string, paramType: string): any {
     }
 }
 
+// Treat runs of uppercase letters as one word, so `getAllUsersFromDB` becomes
+// `get-all-users-from-db` and `XMLParser` becomes `xml-parser`
 function generateEndpointKey(queryName: string): string {
     return queryName
-        .replace(/([A-Z])/g, '-$1')
-        .toLowerCase()
-        .replace(/^-/, '');
+        .replace(/([a-z0-9])([A-Z])/g, '$1-$2')
+        .replace(/([A-Z]+)([A-Z][a-z])/g, '$1-$2')
+        .toLowerCase();
 }
 
 export async function fetchEndpoints(): Promise<Record<string, EndpointConfig>> {